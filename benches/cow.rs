@@ -0,0 +1,83 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lo_::{
+    capitalize, capitalize_cow, capitalize_into, lower_first, lower_first_cow, lower_first_into,
+    str_pad, str_pad_cow, str_pad_into, truncate_middle, truncate_middle_cow, truncate_middle_into,
+    upper_first, upper_first_cow, upper_first_into, Alignment,
+};
+use std::borrow::Cow;
+
+/// Benchmarks the three variants of one function on `inputs`: one that is
+/// already in shape, so `_cow` can borrow it, and one that needs changes.
+fn compare<A, C, I>(c: &mut Criterion, name: &str, inputs: [&str; 2], alloc: A, cow: C, into: I)
+where
+    A: Fn(&str) -> String,
+    C: for<'a> Fn(&'a str) -> Cow<'a, str>,
+    I: Fn(&str, &mut String),
+{
+    for (label, input) in ["unchanged", "changed"].into_iter().zip(inputs) {
+        let mut out = String::with_capacity(64);
+        let mut group = c.benchmark_group(format!("{name}/{label}"));
+        group.bench_function("allocating", |b| b.iter(|| alloc(black_box(input))));
+        group.bench_function("cow", |b| b.iter(|| cow(black_box(input)).len()));
+        group.bench_function("into reused buffer", |b| {
+            b.iter(|| {
+                out.clear();
+                into(black_box(input), &mut out);
+                out.len()
+            })
+        });
+        group.finish();
+    }
+}
+
+fn bench_case(c: &mut Criterion) {
+    compare(
+        c,
+        "capitalize",
+        ["Ready to ship", "rEADY TO SHIP"],
+        capitalize,
+        capitalize_cow,
+        capitalize_into,
+    );
+    compare(
+        c,
+        "lower_first",
+        ["fooBar", "FooBar"],
+        lower_first,
+        lower_first_cow,
+        lower_first_into,
+    );
+    compare(
+        c,
+        "upper_first",
+        ["FooBar", "fooBar"],
+        upper_first,
+        upper_first_cow,
+        upper_first_into,
+    );
+}
+
+fn bench_layout(c: &mut Criterion) {
+    compare(
+        c,
+        "str_pad",
+        ["12345", "42"],
+        |s| str_pad(s, 5, "0", Alignment::Left),
+        |s| str_pad_cow(s, 5, "0", Alignment::Left),
+        |s, out| str_pad_into(s, 5, "0", Alignment::Left, out),
+    );
+    compare(
+        c,
+        "truncate_middle",
+        [
+            "src/lib.rs",
+            "src/string/very/deeply/nested/module/path/lib.rs",
+        ],
+        |s| truncate_middle(s, 24),
+        |s| truncate_middle_cow(s, 24),
+        |s, out| truncate_middle_into(s, 24, out),
+    );
+}
+
+criterion_group!(benches, bench_case, bench_layout);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use lo_::{deburr, slugify, template, words_with_pattern, RegexCache};
+use regex::Regex;
+use std::collections::HashMap;
+
+fn bench_template(c: &mut Criterion) {
+    let txt = "Hi {name}, {count} new {lang} crates!";
+    let values = HashMap::from([("name", "Ragnar"), ("lang", "Rust"), ("count", "3")]);
+    let mut group = c.benchmark_group("template");
+    // What `template` did before its regex was compiled once: one regex per key.
+    group.bench_function("compile per call", |b| {
+        b.iter(|| {
+            let mut out = black_box(txt).to_string();
+            for (key, value) in &values {
+                let re = Regex::new(&format!("\\{{{key}\\}}")).unwrap();
+                out = re.replace_all(&out, *value).into_owned();
+            }
+            out
+        })
+    });
+    group.bench_function("static regex", |b| {
+        b.iter(|| template(black_box(txt), &values))
+    });
+    group.finish();
+}
+
+fn bench_slugify(c: &mut Criterion) {
+    let input = "Crème Brûlée: A Rust Story";
+    let mut group = c.benchmark_group("slugify");
+    group.bench_function("compile per call", |b| {
+        b.iter(|| {
+            let folded = deburr(black_box(input)).to_lowercase();
+            let re = Regex::new(r"[\p{L}\p{N}]+").unwrap();
+            re.find_iter(&folded)
+                .map(|m| m.as_str())
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+    });
+    group.bench_function("static regex", |b| b.iter(|| slugify(black_box(input))));
+    group.finish();
+}
+
+fn bench_words_with_pattern(c: &mut Criterion) {
+    let input = "fred, barney, & pebbles";
+    let mut group = c.benchmark_group("words_with_pattern");
+    group.bench_function("compile per call", |b| {
+        b.iter(|| words_with_pattern(black_box(input), Some(Regex::new("[^, ]+").unwrap())))
+    });
+    let cache = RegexCache::new();
+    group.bench_function("regex cache", |b| {
+        b.iter(|| words_with_pattern(black_box(input), Some(cache.get("[^, ]+").unwrap())))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_template,
+    bench_slugify,
+    bench_words_with_pattern
+);
+criterion_main!(benches);
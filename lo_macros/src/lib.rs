@@ -0,0 +1,279 @@
+//! Procedural macros for [`lo_`](https://docs.rs/lo_). Use them through `lo_`
+//! with the `macros` feature enabled rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Error, Expr, Ident, LitStr, Token};
+
+// Filters implemented by a `lo_` function of the same name. Keep in sync with
+// the runtime filters of `lo_::template`.
+const LO_FILTERS: &[&str] = &[
+    "camel_case",
+    "snake_case",
+    "kebab_case",
+    "screaming_snake_case",
+    "title_case",
+    "capitalize",
+    "lower_first",
+    "upper_first",
+    "deburr",
+    "slugify",
+    "pluralize",
+    "singularize",
+    "humanize",
+    "escape_html",
+    "escape_xml",
+    "json_escape",
+    "percent_encode",
+    "shell_quote",
+];
+
+// Filters implemented by `str` methods.
+const STR_FILTERS: &[&str] = &["upper", "lower", "trim"];
+
+/// Builds a `String` from a template, checking placeholders at compile time.
+///
+/// Placeholders use the syntax of [`lo_::template`](https://docs.rs/lo_/latest/lo_/fn.template.html):
+/// `{name}`, optionally followed by filters applied left to right, as in
+/// `{name|trim|title_case}`. Spaces around the key and the filters are ignored. Values are named arguments of any type that
+/// implements `Display`, and each is evaluated once, in order.
+///
+/// Every placeholder whose key is an identifier must have an argument, every
+/// filter must exist and every argument must be used; anything else is a
+/// compile error. Braces around text that is not an identifier, such as
+/// `{"a": 1}`, are kept as they are, just like `template` keeps unknown keys.
+///
+/// The macro expands to plain `String` building with direct calls to the
+/// filter functions; no regex or template parsing happens at runtime.
+///
+/// # Example
+/// ```rust
+/// use lo_::lo_template;
+///
+/// struct User { name: &'static str, visits: u32 }
+/// let user = User { name: "ada lovelace", visits: 3 };
+///
+/// let greeting = lo_template!(
+///     "Hello {name|title_case}, visit #{visits}",
+///     name = user.name,
+///     visits = user.visits + 1,
+/// );
+/// assert_eq!(greeting, "Hello Ada Lovelace, visit #4");
+///
+/// ```
+///
+/// A misspelled placeholder does not compile:
+/// ```compile_fail
+/// let s = lo_::lo_template!("Hello {nmae}", name = "Ada");
+/// ```
+///
+/// Nor does an unknown filter:
+/// ```compile_fail
+/// let s = lo_::lo_template!("Hello {name|shout}", name = "Ada");
+/// ```
+#[proc_macro]
+pub fn lo_template(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TemplateInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct TemplateInput {
+    template: LitStr,
+    args: Vec<Arg>,
+}
+
+struct Arg {
+    name: Ident,
+    value: Expr,
+}
+
+impl Parse for TemplateInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let template = input.parse()?;
+        let mut args = Vec::new();
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            args.extend(Punctuated::<Arg, Token![,]>::parse_terminated(input)?);
+        }
+        Ok(TemplateInput { template, args })
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(Arg { name, value })
+    }
+}
+
+enum Segment {
+    Text(String),
+    Placeholder { arg: usize, filters: Vec<String> },
+}
+
+fn expand(input: TemplateInput) -> syn::Result<TokenStream2> {
+    let TemplateInput { template, args } = input;
+    for (i, arg) in args.iter().enumerate() {
+        if args[..i].iter().any(|other| other.name == arg.name) {
+            return Err(Error::new(
+                arg.name.span(),
+                format!("duplicate argument `{}`", arg.name),
+            ));
+        }
+    }
+
+    let segments = parse_template(&template, &args)?;
+    for (i, arg) in args.iter().enumerate() {
+        let used = segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Placeholder { arg, .. } if *arg == i));
+        if !used {
+            return Err(Error::new(
+                arg.name.span(),
+                format!("argument `{}` is never used", arg.name),
+            ));
+        }
+    }
+
+    let out = Ident::new("__lo_out", Span::mixed_site());
+    let value = Ident::new("__lo_value", Span::mixed_site());
+    let names: Vec<Ident> = (0..args.len())
+        .map(|i| format_ident!("__lo_arg{}", i, span = Span::mixed_site()))
+        .collect();
+    let values = args.iter().map(|arg| &arg.value);
+    let mut capacity = 0;
+    let statements: Vec<TokenStream2> = segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => {
+                capacity += text.len();
+                quote!(#out.push_str(#text);)
+            }
+            Segment::Placeholder { arg, filters } if filters.is_empty() => {
+                let name = &names[*arg];
+                quote! {
+                    let _ = ::std::fmt::Write::write_fmt(&mut #out, ::std::format_args!("{}", #name));
+                }
+            }
+            Segment::Placeholder { arg, filters } => {
+                let name = &names[*arg];
+                let calls = filters.iter().map(|filter| filter_call(filter, &value));
+                quote! {{
+                    let #value = ::std::string::ToString::to_string(#name);
+                    #(let #value = #calls;)*
+                    #out.push_str(&#value);
+                }}
+            }
+        })
+        .collect();
+
+    Ok(quote! {
+        match (#(&(#values),)*) {
+            (#(#names,)*) => {
+                let mut #out = ::std::string::String::with_capacity(#capacity);
+                #(#statements)*
+                #out
+            }
+        }
+    })
+}
+
+/// Splits the template into text and placeholders. Like the runtime
+/// `template`, a placeholder is a `{`, text without braces and a `}`.
+fn parse_template(template: &LitStr, args: &[Arg]) -> syn::Result<Vec<Segment>> {
+    let source = template.value();
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = source.as_str();
+    while let Some(open) = rest.find('{') {
+        let after = &rest[open + 1..];
+        match after.find(['{', '}']) {
+            Some(close) if after.as_bytes()[close] == b'}' => {
+                text.push_str(&rest[..open]);
+                match placeholder(&after[..close], args, template)? {
+                    Some(segment) => {
+                        if !text.is_empty() {
+                            segments.push(Segment::Text(std::mem::take(&mut text)));
+                        }
+                        segments.push(segment);
+                    }
+                    None => text.push_str(&rest[open..open + close + 2]),
+                }
+                rest = &after[close + 1..];
+            }
+            _ => {
+                text.push_str(&rest[..=open]);
+                rest = after;
+            }
+        }
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+/// Resolves the contents of a placeholder, or returns `None` when its key is
+/// not an identifier and the braces are plain text.
+fn placeholder(content: &str, args: &[Arg], template: &LitStr) -> syn::Result<Option<Segment>> {
+    let (key, filters) = match content.split_once('|') {
+        Some((key, filters)) => (key.trim(), filters.split('|').map(str::trim).collect()),
+        None => (content.trim(), Vec::new()),
+    };
+    if !is_identifier(key) {
+        return Ok(None);
+    }
+    let arg = args.iter().position(|arg| arg.name == key).ok_or_else(|| {
+        Error::new(
+            template.span(),
+            format!("no argument named `{key}` for placeholder `{{{content}}}`"),
+        )
+    })?;
+    for filter in &filters {
+        if !LO_FILTERS.contains(filter) && !STR_FILTERS.contains(filter) {
+            return Err(Error::new(
+                template.span(),
+                format!(
+                    "unknown filter `{filter}` in placeholder `{{{content}}}`; expected one of: {}",
+                    [LO_FILTERS, STR_FILTERS].concat().join(", ")
+                ),
+            ));
+        }
+    }
+    Ok(Some(Segment::Placeholder {
+        arg,
+        filters: filters.into_iter().map(String::from).collect(),
+    }))
+}
+
+fn filter_call(filter: &str, value: &Ident) -> TokenStream2 {
+    match filter {
+        "upper" => quote!(::core::primitive::str::to_uppercase(&#value)),
+        "lower" => quote!(::core::primitive::str::to_lowercase(&#value)),
+        "trim" => quote! {
+            ::std::string::ToString::to_string(::core::primitive::str::trim(&#value))
+        },
+        _ => {
+            let function = Ident::new(filter, Span::call_site());
+            quote!(::lo_::#function(&#value))
+        }
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) if first == '_' || first.is_alphabetic() => {
+            chars.all(|c| c == '_' || c.is_alphanumeric())
+        }
+        _ => false,
+    }
+}
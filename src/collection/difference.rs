@@ -46,6 +46,6 @@ mod tests {
         let array1: Vec<i32> = vec![];
         let array2: Vec<i32> = vec![];
         let result = difference(&array1, &array2);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 }
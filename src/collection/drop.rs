@@ -30,7 +30,7 @@ mod tests {
         let input: Vec<i32> = vec![];
         let n = 0;
         let result = drop(&input, n);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 
     #[test]
@@ -54,7 +54,7 @@ mod tests {
         let input = vec![1, 2, 3, 4, 5];
         let n = 5;
         let result = drop(&input, n);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 
     #[test]
@@ -62,6 +62,6 @@ mod tests {
         let input = vec![1, 2, 3, 4, 5];
         let n = 10;
         let result = drop(&input, n);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 }
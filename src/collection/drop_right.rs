@@ -27,7 +27,7 @@ mod tests {
         let input: Vec<i32> = vec![];
         let n = 0;
         let result = drop_right(&input, n);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 
     #[test]
@@ -51,7 +51,7 @@ mod tests {
         let input = vec![1, 2, 3, 4, 5];
         let n = 5;
         let result = drop_right(&input, n);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 
     #[test]
@@ -59,6 +59,6 @@ mod tests {
         let input = vec![1, 2, 3, 4, 5];
         let n = 10;
         let result = drop_right(&input, n);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 }
@@ -46,7 +46,7 @@ mod tests {
         let array1 = vec![1, 2, 3];
         let array2 = vec![4, 5, 6];
         let result = intersection(&array1, &array2);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 
     #[test]
@@ -54,6 +54,6 @@ mod tests {
         let array1: Vec<i32> = vec![];
         let array2: Vec<i32> = vec![];
         let result = intersection(&array1, &array2);
-        assert_eq!(result, vec![]);
+        assert_eq!(result, Vec::<i32>::new());
     }
 }
@@ -0,0 +1,196 @@
+use crate::string::{convert_case, Case};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// Decides what happens when two keys convert to the same name.
+///
+/// Keys are visited in a deterministic order: ascending order of the original
+/// keys for a `HashMap`, and the map's own iteration order for JSON objects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyCollision {
+    /// Keep the value of the first key visited.
+    KeepFirst,
+    /// Keep the value of the last key visited.
+    #[default]
+    KeepLast,
+    /// Fail with a [`KeyCollisionError`].
+    Error,
+}
+
+/// Returned when [`KeyCollision::Error`] is used and two keys convert to the same name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyCollisionError {
+    /// The converted key both originals map to.
+    pub key: String,
+    /// The original key that was visited first.
+    pub first: String,
+    /// The original key that collided with it.
+    pub second: String,
+}
+
+impl fmt::Display for KeyCollisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "keys {:?} and {:?} both convert to {:?}",
+            self.first, self.second, self.key
+        )
+    }
+}
+
+impl Error for KeyCollisionError {}
+
+/// Returns a new map with every key converted to the given [`Case`].
+///
+/// When two keys convert to the same name, the value of the last one in
+/// ascending key order is kept. Use [`map_keys_case_with`] to pick another policy.
+///
+/// # Example
+/// ```rust
+/// use lo_::{map_keys_case, Case};
+/// use std::collections::HashMap;
+/// let map = HashMap::from([("userId".to_string(), 1), ("createdAt".to_string(), 2)]);
+/// let result = map_keys_case(&map, Case::Snake);
+/// assert_eq!(result, HashMap::from([("user_id".to_string(), 1), ("created_at".to_string(), 2)]));
+///
+/// ```
+pub fn map_keys_case<K, V>(map: &HashMap<K, V>, case: Case) -> HashMap<String, V>
+where
+    K: AsRef<str>,
+    V: Clone,
+{
+    match map_keys_case_with(map, case, KeyCollision::KeepLast) {
+        Ok(result) => result,
+        Err(_) => unreachable!("KeepLast never reports a collision"),
+    }
+}
+
+/// Returns a new map with every key converted to the given [`Case`],
+/// resolving colliding keys with the given [`KeyCollision`] policy.
+///
+/// # Example
+/// ```rust
+/// use lo_::{map_keys_case_with, Case, KeyCollision};
+/// use std::collections::HashMap;
+/// let map = HashMap::from([("user_id", 1), ("user-id", 2)]);
+///
+/// // "user-id" sorts before "user_id", so it is visited first.
+/// let first = map_keys_case_with(&map, Case::Camel, KeyCollision::KeepFirst).unwrap();
+/// assert_eq!(first, HashMap::from([("userId".to_string(), 2)]));
+///
+/// let err = map_keys_case_with(&map, Case::Camel, KeyCollision::Error).unwrap_err();
+/// assert_eq!(err.key, "userId");
+///
+/// ```
+pub fn map_keys_case_with<K, V>(
+    map: &HashMap<K, V>,
+    case: Case,
+    policy: KeyCollision,
+) -> Result<HashMap<String, V>, KeyCollisionError>
+where
+    K: AsRef<str>,
+    V: Clone,
+{
+    let mut entries: Vec<(&str, &V)> = map.iter().map(|(k, v)| (k.as_ref(), v)).collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    let converted = convert_keys(
+        entries.into_iter().map(|(k, v)| (k.to_string(), v.clone())),
+        case,
+        policy,
+    )?;
+    Ok(converted.into_iter().collect())
+}
+
+/// Converts the keys of `entries` in visiting order, applying the collision policy.
+/// The output keeps the order in which converted keys were first seen.
+pub(crate) fn convert_keys<V, I>(
+    entries: I,
+    case: Case,
+    policy: KeyCollision,
+) -> Result<Vec<(String, V)>, KeyCollisionError>
+where
+    I: IntoIterator<Item = (String, V)>,
+{
+    let mut result: Vec<(String, V)> = Vec::new();
+    let mut seen: HashMap<String, (usize, String)> = HashMap::new();
+
+    for (original, value) in entries {
+        let key = convert_case(&original, case);
+        match seen.get(&key) {
+            Some((index, first)) => match policy {
+                KeyCollision::KeepFirst => {}
+                KeyCollision::KeepLast => result[*index].1 = value,
+                KeyCollision::Error => {
+                    return Err(KeyCollisionError {
+                        key,
+                        first: first.clone(),
+                        second: original,
+                    })
+                }
+            },
+            None => {
+                seen.insert(key.clone(), (result.len(), original));
+                result.push((key, value));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_keys_case_camel() {
+        let map = HashMap::from([("first_name", "Ada"), ("last_name", "Lovelace")]);
+        let result = map_keys_case(&map, Case::Camel);
+        let expected = HashMap::from([
+            ("firstName".to_string(), "Ada"),
+            ("lastName".to_string(), "Lovelace"),
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_map_keys_case_kebab() {
+        let map = HashMap::from([("max age".to_string(), 30)]);
+        let result = map_keys_case(&map, Case::Kebab);
+        assert_eq!(result, HashMap::from([("max-age".to_string(), 30)]));
+    }
+
+    #[test]
+    fn test_map_keys_case_collision_policies() {
+        // "userId" sorts before "user_id", so it is visited first.
+        let map = HashMap::from([("user_id", 1), ("userId", 2)]);
+
+        let last = map_keys_case(&map, Case::Snake);
+        assert_eq!(last, HashMap::from([("user_id".to_string(), 1)]));
+
+        let first = map_keys_case_with(&map, Case::Snake, KeyCollision::KeepFirst).unwrap();
+        assert_eq!(first, HashMap::from([("user_id".to_string(), 2)]));
+
+        let err = map_keys_case_with(&map, Case::Snake, KeyCollision::Error).unwrap_err();
+        assert_eq!(
+            err,
+            KeyCollisionError {
+                key: "user_id".to_string(),
+                first: "userId".to_string(),
+                second: "user_id".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            r#"keys "userId" and "user_id" both convert to "user_id""#
+        );
+    }
+
+    #[test]
+    fn test_map_keys_case_empty() {
+        let map: HashMap<String, i32> = HashMap::new();
+        assert!(map_keys_case(&map, Case::Camel).is_empty());
+    }
+}
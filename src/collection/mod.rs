@@ -39,3 +39,10 @@ pub use assign::*;
 
 mod subset;
 pub use subset::*;
+
+mod map_keys_case;
+pub use map_keys_case::*;
+
+mod transform_keys;
+#[cfg(feature = "serde_json")]
+pub use transform_keys::*;
@@ -13,8 +13,8 @@
 /// assert_eq!(subset(&input, 2, 3), &[2, 3, 4]);
 /// assert_eq!(subset(&input, -4, 3), &[1, 2, 3]);
 /// assert_eq!(subset(&input, -2, usize::MAX), &[3, 4]);
-/// assert_eq!(subset(&input, 10, 5), &[]);
-/// assert_eq!(subset::<i32>(&[], 0, 3), &[]);
+/// assert_eq!(subset(&input, 10, 5), &[] as &[i32]);
+/// assert_eq!(subset::<i32>(&[], 0, 3), &[] as &[i32]);
 ///
 /// ```
 pub fn subset<T>(slice: &[T], offset: isize, length: usize) -> &[T] {
@@ -47,7 +47,7 @@ mod tests {
         let input = [0, 1, 2, 3, 4];
         assert_eq!(subset(&input, 2, 3), &[2, 3, 4]);
         assert_eq!(subset(&input, 4, 10), &[4]);
-        assert_eq!(subset(&input, 5, 1), &[]);
+        assert_eq!(subset(&input, 5, 1), &[] as &[i32]);
     }
 
     #[test]
@@ -61,7 +61,7 @@ mod tests {
     #[test]
     fn test_zero_length_or_empty_input() {
         let input = [1, 2, 3];
-        assert_eq!(subset(&input, 1, 0), &[]);
-        assert_eq!(subset::<i32>(&[], 0, 3), &[]);
+        assert_eq!(subset(&input, 1, 0), &[] as &[i32]);
+        assert_eq!(subset::<i32>(&[], 0, 3), &[] as &[i32]);
     }
 }
@@ -0,0 +1,126 @@
+#[cfg(feature = "serde_json")]
+use crate::collection::{convert_keys, KeyCollision, KeyCollisionError};
+#[cfg(feature = "serde_json")]
+use crate::string::Case;
+#[cfg(feature = "serde_json")]
+use serde_json::{Map, Value};
+
+/// Recursively converts every object key in a JSON value to the given [`Case`].
+///
+/// Nested objects and objects inside arrays are converted too; string values are
+/// left untouched. When two keys of the same object convert to the same name, the
+/// last one in the object's iteration order wins.
+///
+/// Requires the `serde_json` feature.
+///
+/// # Example
+/// ```rust
+/// use lo_::{transform_keys, Case};
+/// use serde_json::json;
+///
+/// let input = json!({ "userId": 1, "profile": { "firstName": "Ada" }, "tags": [{ "tagName": "x" }] });
+/// let output = transform_keys(input, Case::Snake);
+/// assert_eq!(output, json!({ "user_id": 1, "profile": { "first_name": "Ada" }, "tags": [{ "tag_name": "x" }] }));
+///
+/// ```
+///
+/// # Features
+/// This function is only available when the `serde_json` feature is enabled.
+#[cfg(feature = "serde_json")]
+pub fn transform_keys(value: Value, case: Case) -> Value {
+    match transform_keys_with(value, case, KeyCollision::KeepLast) {
+        Ok(value) => value,
+        Err(_) => unreachable!("KeepLast never reports a collision"),
+    }
+}
+
+/// Recursively converts every object key in a JSON value to the given [`Case`],
+/// resolving colliding keys with the given [`KeyCollision`] policy.
+///
+/// Requires the `serde_json` feature.
+///
+/// # Example
+/// ```rust
+/// use lo_::{transform_keys_with, Case, KeyCollision};
+/// use serde_json::json;
+///
+/// let input = json!({ "nested": { "user-id": 1, "user_id": 2 } });
+/// let err = transform_keys_with(input, Case::Camel, KeyCollision::Error).unwrap_err();
+/// assert_eq!(err.key, "userId");
+///
+/// ```
+///
+/// # Features
+/// This function is only available when the `serde_json` feature is enabled.
+#[cfg(feature = "serde_json")]
+pub fn transform_keys_with(
+    value: Value,
+    case: Case,
+    policy: KeyCollision,
+) -> Result<Value, KeyCollisionError> {
+    match value {
+        Value::Object(map) => {
+            let mut entries = Vec::with_capacity(map.len());
+            for (key, value) in map {
+                entries.push((key, transform_keys_with(value, case, policy)?));
+            }
+            let converted = convert_keys(entries, case, policy)?;
+            Ok(Value::Object(converted.into_iter().collect::<Map<_, _>>()))
+        }
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| transform_keys_with(item, case, policy))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        other => Ok(other),
+    }
+}
+
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transform_keys_nested() {
+        let input = json!({
+            "first_name": "Ada",
+            "address": { "street_name": "Main", "zip_code": "123" },
+            "orders": [{ "order_id": 1 }, { "order_id": 2 }]
+        });
+        let expected = json!({
+            "firstName": "Ada",
+            "address": { "streetName": "Main", "zipCode": "123" },
+            "orders": [{ "orderId": 1 }, { "orderId": 2 }]
+        });
+        assert_eq!(transform_keys(input, Case::Camel), expected);
+    }
+
+    #[test]
+    fn test_transform_keys_leaves_values() {
+        let input = json!({ "someKey": "someValue", "list": ["camelCase"] });
+        let expected = json!({ "some_key": "someValue", "list": ["camelCase"] });
+        assert_eq!(transform_keys(input, Case::Snake), expected);
+    }
+
+    #[test]
+    fn test_transform_keys_scalars() {
+        assert_eq!(transform_keys(json!(42), Case::Snake), json!(42));
+        assert_eq!(transform_keys(json!(null), Case::Snake), json!(null));
+    }
+
+    #[test]
+    fn test_transform_keys_collision() {
+        let input = json!({ "user id": 1, "user_id": 2 });
+
+        let first = transform_keys_with(input.clone(), Case::Snake, KeyCollision::KeepFirst);
+        assert_eq!(first, Ok(json!({ "user_id": 1 })));
+
+        let last = transform_keys_with(input.clone(), Case::Snake, KeyCollision::KeepLast);
+        assert_eq!(last, Ok(json!({ "user_id": 2 })));
+
+        let err = transform_keys_with(input, Case::Snake, KeyCollision::Error).unwrap_err();
+        assert_eq!(err.first, "user id");
+        assert_eq!(err.second, "user_id");
+    }
+}
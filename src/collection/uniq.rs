@@ -33,6 +33,6 @@ mod tests {
     fn test_empty_input() {
         let input: Vec<i32> = vec![];
         let output = uniq(input);
-        assert_eq!(output, vec![]);
+        assert_eq!(output, Vec::<i32>::new());
     }
 }
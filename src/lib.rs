@@ -9,3 +9,10 @@ pub use collection::*;
 pub mod general;
 #[cfg(feature = "general")]
 pub use general::*;
+
+#[cfg(feature = "macros")]
+pub use lo_macros::lo_template;
+
+// Lets `lo_template!` expansions, which name `::lo_`, compile in this crate's tests.
+#[cfg(all(test, feature = "macros"))]
+extern crate self as lo_;
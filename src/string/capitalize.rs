@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 /// Converts the first character of string to upper case and the remaining to lower case.
 ///
 /// # Example
@@ -8,19 +10,49 @@
 /// ```
 ///
 pub fn capitalize(string: &str) -> String {
-    let mut result = String::with_capacity(string.len());
-    let mut is_first_char = true;
+    capitalize_cow(string).into_owned()
+}
 
-    for c in string.chars() {
-        if is_first_char {
-            result.push(c.to_ascii_uppercase());
-            is_first_char = false;
-        } else {
-            result.push(c.to_ascii_lowercase());
-        }
+/// Like [`capitalize`], but borrows the input when it is already capitalized.
+///
+/// # Example
+/// ```rust
+/// use lo_::capitalize_cow;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(capitalize_cow("Fred"), Cow::Borrowed(_)));
+/// assert_eq!(capitalize_cow("fRED"), "Fred");
+///
+/// ```
+pub fn capitalize_cow(string: &str) -> Cow<'_, str> {
+    let mut chars = string.chars();
+    let first_ok = chars.next().is_none_or(|c| !c.is_ascii_lowercase());
+    if first_ok && !chars.any(|c| c.is_ascii_uppercase()) {
+        return Cow::Borrowed(string);
     }
 
-    result
+    let mut result = String::with_capacity(string.len());
+    capitalize_into(string, &mut result);
+    Cow::Owned(result)
+}
+
+/// Like [`capitalize`], but appends the result to `out` instead of allocating.
+///
+/// # Example
+/// ```rust
+/// use lo_::capitalize_into;
+///
+/// let mut out = String::from("Name: ");
+/// capitalize_into("fRED", &mut out);
+/// assert_eq!(out, "Name: Fred");
+///
+/// ```
+pub fn capitalize_into(string: &str, out: &mut String) {
+    let mut chars = string.chars();
+    if let Some(first) = chars.next() {
+        out.push(first.to_ascii_uppercase());
+    }
+    out.extend(chars.map(|c| c.to_ascii_lowercase()));
 }
 
 #[cfg(test)]
@@ -56,4 +88,19 @@ mod tests {
         let result = capitalize("mIXeD");
         assert_eq!(result, "Mixed");
     }
+
+    #[test]
+    fn test_capitalize_cow_and_into() {
+        assert!(matches!(capitalize_cow("Hello world"), Cow::Borrowed(_)));
+        assert!(matches!(capitalize_cow(""), Cow::Borrowed(_)));
+        assert!(matches!(capitalize_cow("Hello World"), Cow::Owned(_)));
+        assert_eq!(capitalize_cow("hELLO"), "Hello");
+
+        let mut out = String::with_capacity(32);
+        let capacity = out.capacity();
+        capitalize_into("aB", &mut out);
+        capitalize_into("cD", &mut out);
+        assert_eq!(out, "AbCd");
+        assert_eq!(out.capacity(), capacity);
+    }
 }
@@ -0,0 +1,62 @@
+use crate::string::*;
+
+/// The case styles supported by [`convert_case`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `helloWorld`, see [`camel_case`].
+    Camel,
+    /// `hello_world`, see [`snake_case`].
+    Snake,
+    /// `hello-world`, see [`kebab_case`].
+    Kebab,
+    /// `HELLO_WORLD`, see [`screaming_snake_case`].
+    ScreamingSnake,
+    /// `Hello World`, see [`title_case`].
+    Title,
+}
+
+/// Converts a string to the given [`Case`].
+///
+/// This dispatches to the matching case function, so the result is exactly
+/// what `camel_case`, `snake_case`, etc. would return.
+///
+/// # Example
+/// ```rust
+/// use lo_::{convert_case, Case};
+/// assert_eq!(convert_case("user_id", Case::Camel), "userId");
+/// assert_eq!(convert_case("userId", Case::Snake), "user_id");
+/// assert_eq!(convert_case("user id", Case::Kebab), "user-id");
+///
+/// ```
+pub fn convert_case(s: &str, case: Case) -> String {
+    match case {
+        Case::Camel => camel_case(s),
+        Case::Snake => snake_case(s),
+        Case::Kebab => kebab_case(s),
+        Case::ScreamingSnake => screaming_snake_case(s),
+        Case::Title => title_case(s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_case_dispatch() {
+        assert_eq!(convert_case("created_at", Case::Camel), "createdAt");
+        assert_eq!(convert_case("createdAt", Case::Snake), "created_at");
+        assert_eq!(convert_case("Created At", Case::Kebab), "created-at");
+        assert_eq!(
+            convert_case("created at", Case::ScreamingSnake),
+            "CREATED_AT"
+        );
+        assert_eq!(convert_case("created at", Case::Title), "Created At");
+    }
+
+    #[test]
+    fn test_convert_case_empty() {
+        assert_eq!(convert_case("", Case::Camel), "");
+        assert_eq!(convert_case("", Case::Snake), "");
+    }
+}
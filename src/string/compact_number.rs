@@ -0,0 +1,61 @@
+use crate::string::format_number::{scale_down, short_decimal};
+
+const SUFFIXES: [&str; 5] = ["", "K", "M", "B", "T"];
+
+/// Formats a number in short form with a `K`, `M`, `B` or `T` suffix and at most one decimal.
+///
+/// The result parses back (rounded) with [`parse_lenient`](crate::parse_lenient).
+///
+/// # Example
+/// ```rust
+/// use lo_::compact_number;
+///
+/// assert_eq!(compact_number(950.0), "950");
+/// assert_eq!(compact_number(1234.0), "1.2K");
+/// assert_eq!(compact_number(3_400_000.0), "3.4M");
+/// assert_eq!(compact_number(-2_000_000_000.0), "-2B");
+///
+/// ```
+pub fn compact_number(n: f64) -> String {
+    if !n.is_finite() {
+        return n.to_string();
+    }
+
+    let (scaled, power) = scale_down(n.abs(), 1000.0, SUFFIXES.len() - 1);
+    let digits = short_decimal(scaled);
+    let sign = if n < 0.0 && digits != "0" { "-" } else { "" };
+    format!("{sign}{digits}{}", SUFFIXES[power])
+}
+
+/// The power of ten for a [`compact_number`] suffix, case-insensitive.
+pub(crate) fn compact_exponent(suffix: &str) -> Option<usize> {
+    let power = SUFFIXES
+        .iter()
+        .skip(1)
+        .position(|s| s.eq_ignore_ascii_case(suffix))?;
+    Some(3 * (power + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::parse_lenient;
+
+    #[test]
+    fn test_compact_number() {
+        assert_eq!(compact_number(0.0), "0");
+        assert_eq!(compact_number(999.0), "999");
+        assert_eq!(compact_number(1000.0), "1K");
+        assert_eq!(compact_number(1550.0), "1.6K");
+        assert_eq!(compact_number(999_960.0), "1M");
+        assert_eq!(compact_number(12.34), "12.3");
+        assert_eq!(compact_number(1.5e15), "1500T");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for n in [0.0, 999.0, 1200.0, 3_400_000.0, -2_000_000_000.0, 7e12] {
+            assert_eq!(parse_lenient::<f64>(&compact_number(n)), Ok(n));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::string::SplitError;
+use std::borrow::Cow;
+
+/// Splits one CSV line into its fields, following the RFC 4180 quoting rules.
+///
+/// A field wrapped in double quotes may contain the delimiter, and `""` inside
+/// it stands for one quote. Quotes inside an unquoted field are kept as is.
+/// Fields without `""` escapes are borrowed from the input.
+///
+/// # Example
+/// ```rust
+/// use lo_::csv_split;
+///
+/// let fields = csv_split(r#"1,"Doe, Jane","say ""hi""",,x"#, ',').unwrap();
+/// assert_eq!(fields, vec!["1", "Doe, Jane", r#"say "hi""#, "", "x"]);
+///
+/// assert!(csv_split(r#""open,field"#, ',').is_err());
+///
+/// ```
+pub fn csv_split(line: &str, delimiter: char) -> Result<Vec<Cow<'_, str>>, SplitError> {
+    let mut fields = Vec::new();
+    let mut rest = line;
+
+    loop {
+        let offset = line.len() - rest.len();
+        let (field, tail) = match rest.strip_prefix('"') {
+            Some(quoted) => quoted_field(quoted, offset)?,
+            None => match rest.find(delimiter) {
+                Some(end) => (Cow::Borrowed(&rest[..end]), &rest[end..]),
+                None => (Cow::Borrowed(rest), ""),
+            },
+        };
+        fields.push(field);
+
+        match tail.strip_prefix(delimiter) {
+            Some(next) => rest = next,
+            None if tail.is_empty() => return Ok(fields),
+            None => {
+                let position = line.len() - tail.len() - 1;
+                return Err(SplitError::TextAfterQuote { position });
+            }
+        }
+    }
+}
+
+/// Reads a quoted field (after its opening quote at byte `offset`), returning it and the rest.
+fn quoted_field(s: &str, offset: usize) -> Result<(Cow<'_, str>, &str), SplitError> {
+    let mut owned: Option<String> = None;
+    let mut start = 0;
+
+    loop {
+        let Some(quote) = s[start..].find('"').map(|i| start + i) else {
+            return Err(SplitError::UnterminatedQuote { position: offset });
+        };
+        if s[quote + 1..].starts_with('"') {
+            let field = owned.get_or_insert_with(String::new);
+            field.push_str(&s[start..=quote]);
+            start = quote + 2;
+            continue;
+        }
+
+        let field = match owned {
+            Some(mut field) => {
+                field.push_str(&s[start..quote]);
+                Cow::Owned(field)
+            }
+            None => Cow::Borrowed(&s[..quote]),
+        };
+        return Ok((field, &s[quote + 1..]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_fields() {
+        let fields = csv_split("a,b,,c,", ',').unwrap();
+        assert_eq!(fields, vec!["a", "b", "", "c", ""]);
+        assert!(fields.iter().all(|f| matches!(f, Cow::Borrowed(_))));
+        assert_eq!(csv_split("", ',').unwrap(), vec![""]);
+    }
+
+    #[test]
+    fn test_quoted_fields() {
+        let fields = csv_split(r#""a;b";"""";"x ""y"" z";"#, ';').unwrap();
+        assert_eq!(fields, vec!["a;b", "\"", r#"x "y" z"#, ""]);
+        assert!(matches!(fields[0], Cow::Borrowed(_)));
+        assert!(matches!(fields[2], Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_bare_quotes_are_kept() {
+        assert_eq!(
+            csv_split(r#"5'10",a"b"#, ',').unwrap(),
+            vec![r#"5'10""#, r#"a"b"#]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            csv_split(r#"a,"open"#, ','),
+            Err(SplitError::UnterminatedQuote { position: 2 })
+        );
+        assert_eq!(
+            csv_split(r#""done"x,y"#, ','),
+            Err(SplitError::TextAfterQuote { position: 5 })
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use unicode_normalization::UnicodeNormalization;
+
+/// Deburrs a string by converting Latin letters to basic Latin and removing
+/// combining diacritical marks, like Lodash's `_.deburr`.
+///
+/// ### Behavior:
+/// - Strips combining diacritics from Latin letters (e.g., "déjà vu" → "deja vu").
+/// - Spells out ligatures and stroked letters (e.g., "æ" → "ae", "œ" → "oe", "ø" → "o",
+///   "đ" → "d", "ł" → "l", "ß" → "ss").
+/// - Folds compatibility forms (e.g., "ﬁ" → "fi", "Ｒｕｓｔ" → "Rust", "²" → "2").
+/// - Keeps other scripts as they are, marks included (e.g., "ά", "が", "한", "हिन्दी").
+///   Use [`transliterate`](crate::transliterate) to romanize Greek and Cyrillic.
+///
+/// ### Examples
+/// ```rust
+/// use lo_::deburr;
+///
+/// assert_eq!(deburr("déjà vu"), "deja vu");
+/// assert_eq!(deburr("Æsir Œuvre"), "Aesir Oeuvre");
+/// assert_eq!(deburr("Łódź ørsted đak"), "Lodz orsted dak");
+///
+/// ```
+pub fn deburr(s: &str) -> String {
+    fold(s, latin)
+}
+
+/// Folds every character with NFKD and spells out the characters found in
+/// `table`. Combining diacritics (U+0300–U+036F) are dropped when they follow a
+/// Latin letter; marks of other scripts are kept and recomposed with NFC, so
+/// "が" or "한" come out whole. Case is kept: a capital letter looked up via its
+/// lowercase form only capitalizes the first ASCII letter (e.g., "Æ" → "Ae").
+pub(crate) fn fold<F>(s: &str, table: F) -> String
+where
+    F: Fn(char) -> Option<&'static str>,
+{
+    let mut result = String::with_capacity(s.len());
+    // Whether the last base character written was Latin, so that a following
+    // combining diacritic belongs to it.
+    let mut after_latin = false;
+
+    for c in s.chars() {
+        if c.is_ascii() {
+            result.push(c);
+            after_latin = c.is_ascii_alphabetic();
+        } else if let Some(ascii) = lookup(c, &table) {
+            result.push_str(&ascii);
+            after_latin = true;
+        } else {
+            for d in c.nfkd() {
+                if d.is_ascii() {
+                    result.push(d);
+                    after_latin = d.is_ascii_alphabetic();
+                } else if let Some(ascii) = lookup(d, &table) {
+                    result.push_str(&ascii);
+                    after_latin = true;
+                } else if is_combining_diacritic(d) {
+                    if !after_latin {
+                        result.push(d);
+                    }
+                } else {
+                    result.push(d);
+                    after_latin = is_latin(d);
+                }
+            }
+        }
+    }
+
+    if result.is_ascii() {
+        result
+    } else {
+        result.nfc().collect()
+    }
+}
+
+/// Returns true for the Combining Diacritical Marks block, the marks Lodash strips.
+fn is_combining_diacritic(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+/// Returns true for letters of the Latin blocks.
+fn is_latin(c: char) -> bool {
+    matches!(c,
+        '\u{aa}' | '\u{ba}'
+        | '\u{c0}'..='\u{24f}'
+        | '\u{1e00}'..='\u{1eff}'
+        | '\u{2c60}'..='\u{2c7f}'
+        | '\u{a720}'..='\u{a7ff}'
+        | '\u{ab30}'..='\u{ab6f}')
+        && c.is_alphabetic()
+}
+
+/// Looks up a character in `table`, keeping its case.
+fn lookup<F>(c: char, table: &F) -> Option<String>
+where
+    F: Fn(char) -> Option<&'static str>,
+{
+    if let Some(ascii) = table(c) {
+        return Some(ascii.to_string());
+    }
+
+    let mut lower = c.to_lowercase();
+    let lowered = lower.next()?;
+    if lower.next().is_some() || lowered == c {
+        return None;
+    }
+
+    let ascii = table(lowered)?;
+    let mut chars = ascii.chars();
+    Some(match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    })
+}
+
+/// ASCII spellings for Latin letters that do not decompose to ASCII under NFKD.
+/// Keys are lowercase unless the letter has no lowercase form.
+pub(crate) fn latin(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'œ' => "oe",
+        'ø' => "o",
+        'đ' => "d",
+        'ð' => "d",
+        'ħ' => "h",
+        'ı' => "i",
+        'ĸ' => "k",
+        'ŀ' => "l",
+        'ł' => "l",
+        'ŉ' => "'n",
+        'ŋ' => "n",
+        'þ' => "th",
+        'ŧ' => "t",
+        'ƒ' => "f",
+        'ƀ' => "b",
+        'ɨ' => "i",
+        'ʉ' => "u",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accents() {
+        assert_eq!(deburr("Crème Brûlée"), "Creme Brulee");
+        assert_eq!(deburr("naïve façade"), "naive facade");
+        assert_eq!(deburr("Ångström"), "Angstrom");
+    }
+
+    #[test]
+    fn test_ligatures_and_stroked_letters() {
+        assert_eq!(deburr("æ Æ œ Œ"), "ae Ae oe Oe");
+        assert_eq!(deburr("ø Ø đ Đ ł Ł"), "o O d D l L");
+        assert_eq!(deburr("Straße þorn"), "Strasse thorn");
+    }
+
+    #[test]
+    fn test_compatibility_forms() {
+        assert_eq!(deburr("ﬁnal ﬂow"), "final flow");
+        assert_eq!(deburr("Ｒｕｓｔ"), "Rust");
+        assert_eq!(deburr("x²"), "x2");
+    }
+
+    #[test]
+    fn test_keeps_other_scripts() {
+        assert_eq!(deburr("Αθήνα"), "Αθήνα");
+        assert_eq!(deburr("йогурт"), "йогурт");
+        assert_eq!(deburr("中文 🚀"), "中文 🚀");
+        assert_eq!(deburr("がぎ ｶﾞ"), "がぎ ガ");
+        assert_eq!(deburr("한국어"), "한국어");
+        assert_eq!(deburr("हिन्दी"), "हिन्दी");
+    }
+
+    #[test]
+    fn test_only_strips_marks_after_latin() {
+        assert_eq!(deburr("e\u{301}te\u{301}"), "ete");
+        assert_eq!(deburr("ệ ǆ"), "e dz");
+        assert_eq!(deburr("α\u{301} 1\u{301}"), "ά 1\u{301}");
+    }
+
+    #[test]
+    fn test_ascii_and_empty() {
+        assert_eq!(deburr("plain text, 123!"), "plain text, 123!");
+        assert_eq!(deburr(""), "");
+    }
+}
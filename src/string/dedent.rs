@@ -0,0 +1,69 @@
+use crate::string::normalize_line_endings::split_line_endings;
+
+/// Removes the leading whitespace common to every non-blank line.
+///
+/// Tabs and spaces are not treated as equivalent: only the literal common
+/// prefix is removed, so `"\t  a"` and `"\t b"` lose `"\t "` and mixed
+/// indentation is never mangled. Whitespace-only lines are emptied and do not
+/// count toward the common prefix. Line endings are kept as they are.
+///
+/// # Example
+/// ```rust
+/// use lo_::dedent;
+///
+/// let fixture = "
+///     fn main() {
+///         run();
+///     }
+/// ";
+/// assert_eq!(dedent(fixture), "\nfn main() {\n    run();\n}\n");
+///
+/// ```
+pub fn dedent(s: &str) -> String {
+    let mut common: Option<&str> = None;
+    for (line, _) in split_line_endings(s) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indent = &line[..line.len() - line.trim_start().len()];
+        common = Some(match common {
+            None => indent,
+            Some(common) => {
+                let shared = common
+                    .char_indices()
+                    .zip(indent.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map_or(common.len().min(indent.len()), |((i, _), _)| i);
+                &common[..shared]
+            }
+        });
+    }
+
+    let strip = common.map_or(0, str::len);
+    let mut out = String::with_capacity(s.len());
+    for (line, end) in split_line_endings(s) {
+        if !line.trim().is_empty() {
+            out.push_str(&line[strip..]);
+        }
+        out.push_str(end);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_tabs_and_spaces() {
+        assert_eq!(dedent("\t  a\n\t b\n"), " a\nb\n");
+        assert_eq!(dedent("\ta\n    b"), "\ta\n    b");
+    }
+
+    #[test]
+    fn test_blank_lines_and_endings() {
+        assert_eq!(dedent("  a\r\n \r\n    b"), "a\r\n\r\n  b");
+        assert_eq!(dedent("   \n  "), "\n");
+        assert_eq!(dedent(""), "");
+    }
+}
@@ -0,0 +1,708 @@
+use crate::string::{words_iter, WordsMode};
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// One operation of a diff. Each holds a contiguous slice of the old (`Equal`,
+/// `Delete`) or new (`Insert`) text, so joining the `Equal` and `Delete` slices
+/// gives back the old text and joining the `Equal` and `Insert` slices the new one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffOp<'a> {
+    /// Text present in both.
+    Equal(&'a str),
+    /// Text only in the old string.
+    Delete(&'a str),
+    /// Text only in the new string.
+    Insert(&'a str),
+}
+
+/// The algorithm used to compute a diff.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiffAlgorithm {
+    /// Myers' O(ND) algorithm: a shortest edit script.
+    #[default]
+    Myers,
+    /// Patience diff: anchors on tokens that are unique in both inputs, which
+    /// often lines up moved or repeated blocks (e.g. `}` lines) more readably.
+    Patience,
+}
+
+/// What [`diff_with`] compares.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiffUnit {
+    /// Lines, including their line endings.
+    #[default]
+    Lines,
+    /// Words as found by [`words`](crate::words), with the text between them as separate tokens.
+    Words,
+    /// Grapheme clusters (user-perceived characters).
+    Chars,
+}
+
+/// Diffs two strings line by line with the Myers algorithm.
+///
+/// # Example
+/// ```rust
+/// use lo_::{diff_lines, DiffOp};
+///
+/// let ops = diff_lines("a\nb\nc\n", "a\nB\nc\n");
+/// assert_eq!(
+///     ops,
+///     vec![
+///         DiffOp::Equal("a\n"),
+///         DiffOp::Delete("b\n"),
+///         DiffOp::Insert("B\n"),
+///         DiffOp::Equal("c\n"),
+///     ]
+/// );
+///
+/// ```
+pub fn diff_lines<'a>(a: &'a str, b: &'a str) -> Vec<DiffOp<'a>> {
+    diff_with(a, b, DiffUnit::Lines, DiffAlgorithm::Myers)
+}
+
+/// Diffs two strings word by word with the Myers algorithm.
+///
+/// # Example
+/// ```rust
+/// use lo_::{diff_words, DiffOp};
+///
+/// let ops = diff_words("the quick fox", "the slow fox");
+/// assert_eq!(
+///     ops,
+///     vec![
+///         DiffOp::Equal("the "),
+///         DiffOp::Delete("quick"),
+///         DiffOp::Insert("slow"),
+///         DiffOp::Equal(" fox"),
+///     ]
+/// );
+///
+/// ```
+pub fn diff_words<'a>(a: &'a str, b: &'a str) -> Vec<DiffOp<'a>> {
+    diff_with(a, b, DiffUnit::Words, DiffAlgorithm::Myers)
+}
+
+/// Diffs two strings character by character with the Myers algorithm.
+///
+/// # Example
+/// ```rust
+/// use lo_::{diff_chars, DiffOp};
+///
+/// let ops = diff_chars("kitten", "sitten");
+/// assert_eq!(
+///     ops,
+///     vec![DiffOp::Delete("k"), DiffOp::Insert("s"), DiffOp::Equal("itten")]
+/// );
+///
+/// ```
+pub fn diff_chars<'a>(a: &'a str, b: &'a str) -> Vec<DiffOp<'a>> {
+    diff_with(a, b, DiffUnit::Chars, DiffAlgorithm::Myers)
+}
+
+/// Diffs two strings by the given [`DiffUnit`] with the given [`DiffAlgorithm`].
+///
+/// Deletions come before insertions within each changed region.
+///
+/// # Example
+/// ```rust
+/// use lo_::{diff_with, render_inline, DiffAlgorithm, DiffUnit};
+///
+/// let ops = diff_with("port = 80", "port = 8080", DiffUnit::Words, DiffAlgorithm::Patience);
+/// assert_eq!(render_inline(&ops), "port = [-80-]{+8080+}");
+///
+/// ```
+pub fn diff_with<'a>(
+    a: &'a str,
+    b: &'a str,
+    unit: DiffUnit,
+    algorithm: DiffAlgorithm,
+) -> Vec<DiffOp<'a>> {
+    let (old, new) = (tokenize(a, unit), tokenize(b, unit));
+    let edits = edits(&old, &new, algorithm);
+    merge(a, &old, b, &new, &edits)
+}
+
+/// Renders a line diff of `a` and `b` in unified format, with `context` unchanged
+/// lines around each change. File headers (`---`/`+++`) are left to the caller.
+///
+/// # Example
+/// ```rust
+/// use lo_::unified_diff;
+///
+/// let old = "host = a\nport = 80\nuser = x\n";
+/// let new = "host = a\nport = 8080\nuser = x\n";
+/// assert_eq!(
+///     unified_diff(old, new, 1),
+///     "@@ -1,3 +1,3 @@\n host = a\n-port = 80\n+port = 8080\n user = x\n"
+/// );
+///
+/// ```
+pub fn unified_diff(a: &str, b: &str, context: usize) -> String {
+    let (old, new) = (tokenize(a, DiffUnit::Lines), tokenize(b, DiffUnit::Lines));
+    let edits = edits(&old, &new, DiffAlgorithm::Myers);
+
+    // (edit, old line index, new line index) for every edit.
+    let mut lines = Vec::with_capacity(edits.len());
+    let (mut i, mut j) = (0, 0);
+    for edit in &edits {
+        lines.push((*edit, i, j));
+        match edit {
+            Edit::Equal => (i, j) = (i + 1, j + 1),
+            Edit::Delete => i += 1,
+            Edit::Insert => j += 1,
+        }
+    }
+
+    let changes: Vec<usize> = (0..lines.len())
+        .filter(|&k| lines[k].0 != Edit::Equal)
+        .collect();
+    let mut out = String::new();
+    let mut k = 0;
+    while k < changes.len() {
+        let mut last = k;
+        while last + 1 < changes.len() && changes[last + 1] - changes[last] <= 2 * context + 1 {
+            last += 1;
+        }
+        let start = changes[k].saturating_sub(context);
+        let end = (changes[last] + context + 1).min(lines.len());
+        let hunk = &lines[start..end];
+
+        let old_count = hunk.iter().filter(|l| l.0 != Edit::Insert).count();
+        let new_count = hunk.iter().filter(|l| l.0 != Edit::Delete).count();
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(hunk[0].1, old_count),
+            hunk_range(hunk[0].2, new_count)
+        ));
+        for (edit, i, j) in hunk {
+            let (sign, line) = match edit {
+                Edit::Equal => (' ', old[*i]),
+                Edit::Delete => ('-', old[*i]),
+                Edit::Insert => ('+', new[*j]),
+            };
+            out.push(sign);
+            out.push_str(line);
+            if !line.ends_with('\n') {
+                out.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+        k = last + 1;
+    }
+    out
+}
+
+/// Renders a diff with inline `[-deleted-]` and `{+inserted+}` markup.
+///
+/// # Example
+/// ```rust
+/// use lo_::{diff_words, render_inline};
+///
+/// let ops = diff_words("debug = true", "debug = false");
+/// assert_eq!(render_inline(&ops), "debug = [-true-]{+false+}");
+///
+/// ```
+pub fn render_inline(ops: &[DiffOp]) -> String {
+    render(ops, ("[-", "-]"), ("{+", "+}"))
+}
+
+/// Renders a diff for terminals, with deletions in red and insertions in green.
+///
+/// # Example
+/// ```rust
+/// use lo_::{diff_chars, render_ansi};
+///
+/// let ops = diff_chars("cat", "cut");
+/// assert_eq!(render_ansi(&ops), "c\x1b[31ma\x1b[0m\x1b[32mu\x1b[0mt");
+///
+/// ```
+pub fn render_ansi(ops: &[DiffOp]) -> String {
+    render(ops, ("\x1b[31m", "\x1b[0m"), ("\x1b[32m", "\x1b[0m"))
+}
+
+fn render(ops: &[DiffOp], delete: (&str, &str), insert: (&str, &str)) -> String {
+    let mut out = String::new();
+    for op in ops {
+        let (text, (open, close)) = match op {
+            DiffOp::Equal(text) => (text, ("", "")),
+            DiffOp::Delete(text) => (text, delete),
+            DiffOp::Insert(text) => (text, insert),
+        };
+        out.push_str(open);
+        out.push_str(text);
+        out.push_str(close);
+    }
+    out
+}
+
+/// `start,count` of a unified diff hunk, where `start` is 1-based.
+fn hunk_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{count}", start + 1),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Splits `s` into contiguous tokens that concatenate back to `s`.
+fn tokenize(s: &str, unit: DiffUnit) -> Vec<&str> {
+    match unit {
+        DiffUnit::Lines => s.split_inclusive('\n').collect(),
+        DiffUnit::Chars => s.graphemes(true).collect(),
+        DiffUnit::Words => {
+            let mut tokens = Vec::new();
+            let mut end = 0;
+            for word in words_iter(s, WordsMode::Simple) {
+                let start = word.as_ptr() as usize - s.as_ptr() as usize;
+                if start > end {
+                    tokens.push(&s[end..start]);
+                }
+                tokens.push(word);
+                end = start + word.len();
+            }
+            if end < s.len() {
+                tokens.push(&s[end..]);
+            }
+            tokens
+        }
+    }
+}
+
+/// Computes the edit script turning `a` into `b`, after trimming their common prefix and suffix.
+fn edits(a: &[&str], b: &[&str], algorithm: DiffAlgorithm) -> Vec<Edit> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    let mut out = vec![Edit::Equal; prefix];
+    if a_mid.is_empty() || b_mid.is_empty() {
+        out.extend(std::iter::repeat_n(Edit::Delete, a_mid.len()));
+        out.extend(std::iter::repeat_n(Edit::Insert, b_mid.len()));
+    } else {
+        match algorithm {
+            DiffAlgorithm::Myers => out.extend(myers(a_mid, b_mid)),
+            DiffAlgorithm::Patience => out.extend(patience(a_mid, b_mid)),
+        }
+    }
+    out.extend(std::iter::repeat_n(Edit::Equal, suffix));
+    out
+}
+
+/// Myers' shortest edit script in linear space, with deletions before insertions.
+///
+/// Rather than keeping every round of the search to backtrack through, this
+/// finds a point in the middle of an optimal path and diffs the two halves on
+/// either side of it, so memory stays O(N + M).
+fn myers(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    let edits = match middle_snake(a, b) {
+        Some((x, y)) => {
+            let mut out = edits(&a[..x], &b[..y], DiffAlgorithm::Myers);
+            out.extend(edits(&a[x..], &b[y..], DiffAlgorithm::Myers));
+            out
+        }
+        None => {
+            let mut out = vec![Edit::Delete; a.len()];
+            out.extend(std::iter::repeat_n(Edit::Insert, b.len()));
+            out
+        }
+    };
+    reorder(edits)
+}
+
+/// Searches forward from the start and backward from the end at the same time
+/// and returns where the two searches meet, a point splitting an optimal edit
+/// path in two. Returns `None` when `a` and `b` have no token in common.
+fn middle_snake(a: &[&str], b: &[&str]) -> Option<(usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max_d = (n + m + 1) / 2;
+    let offset = max_d;
+    let len = 2 * max_d as usize + 2;
+    // Furthest x reached on each diagonal, forward and backward; -1 when unvisited.
+    let mut forward = vec![-1isize; len];
+    let mut backward = vec![-1isize; len];
+    forward[offset as usize + 1] = 0;
+    backward[offset as usize + 1] = 0;
+    let delta = n - m;
+    // With an odd delta the searches meet while searching forward, otherwise backward.
+    let odd = delta % 2 != 0;
+    // Diagonals that have left the grid are skipped in later rounds.
+    let (mut k1_start, mut k1_end, mut k2_start, mut k2_end) = (0, 0, 0, 0);
+
+    for d in 0..max_d {
+        for k1 in (-d + k1_start..=d - k1_end).step_by(2) {
+            let i = (offset + k1) as usize;
+            let mut x1 = if k1 == -d || (k1 != d && forward[i - 1] < forward[i + 1]) {
+                forward[i + 1]
+            } else {
+                forward[i - 1] + 1
+            };
+            let mut y1 = x1 - k1;
+            while x1 < n && y1 < m && a[x1 as usize] == b[y1 as usize] {
+                x1 += 1;
+                y1 += 1;
+            }
+            forward[i] = x1;
+            if x1 > n {
+                k1_end += 2;
+            } else if y1 > m {
+                k1_start += 2;
+            } else if odd {
+                let j = offset + delta - k1;
+                if (0..len as isize).contains(&j) && backward[j as usize] != -1 {
+                    let x2 = n - backward[j as usize];
+                    if x1 >= x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+        }
+
+        for k2 in (-d + k2_start..=d - k2_end).step_by(2) {
+            let i = (offset + k2) as usize;
+            let mut x2 = if k2 == -d || (k2 != d && backward[i - 1] < backward[i + 1]) {
+                backward[i + 1]
+            } else {
+                backward[i - 1] + 1
+            };
+            let mut y2 = x2 - k2;
+            while x2 < n && y2 < m && a[(n - x2 - 1) as usize] == b[(m - y2 - 1) as usize] {
+                x2 += 1;
+                y2 += 1;
+            }
+            backward[i] = x2;
+            if x2 > n {
+                k2_end += 2;
+            } else if y2 > m {
+                k2_start += 2;
+            } else if !odd {
+                let j = offset + delta - k2;
+                if (0..len as isize).contains(&j) && forward[j as usize] != -1 {
+                    let x1 = forward[j as usize];
+                    let y1 = offset + x1 - j;
+                    if x1 >= n - x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Patience diff: aligns tokens unique to both sides, then diffs the gaps between them.
+fn patience(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    // token -> (count in a, index in a, count in b, index in b)
+    let mut seen: HashMap<&str, (usize, usize, usize, usize)> = HashMap::new();
+    for (i, token) in a.iter().enumerate() {
+        let entry = seen.entry(token).or_default();
+        entry.0 += 1;
+        entry.1 = i;
+    }
+    for (j, token) in b.iter().enumerate() {
+        if let Some(entry) = seen.get_mut(token) {
+            entry.2 += 1;
+            entry.3 = j;
+        }
+    }
+    let mut pairs: Vec<(usize, usize)> = seen
+        .values()
+        .filter(|(ca, _, cb, _)| *ca == 1 && *cb == 1)
+        .map(|(_, i, _, j)| (*i, *j))
+        .collect();
+    pairs.sort_unstable();
+
+    let anchors = longest_increasing(&pairs);
+    if anchors.is_empty() {
+        return myers(a, b);
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    for (ai, bj) in anchors.into_iter().chain([(a.len(), b.len())]) {
+        out.extend(edits(&a[i..ai], &b[j..bj], DiffAlgorithm::Patience));
+        if ai < a.len() {
+            out.push(Edit::Equal);
+        }
+        (i, j) = (ai + 1, bj + 1);
+    }
+    out
+}
+
+/// The longest run of `pairs` (sorted by `.0`) whose `.1` values increase, by patience sorting.
+fn longest_increasing(pairs: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    // tops[p]: index into `pairs` of the top card of pile p; back[i]: the card below pairs[i].
+    let mut tops: Vec<usize> = Vec::new();
+    let mut back: Vec<Option<usize>> = vec![None; pairs.len()];
+    for (i, pair) in pairs.iter().enumerate() {
+        let pile = tops.partition_point(|&t| pairs[t].1 < pair.1);
+        back[i] = pile.checked_sub(1).map(|p| tops[p]);
+        if pile == tops.len() {
+            tops.push(i);
+        } else {
+            tops[pile] = i;
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut cursor = tops.last().copied();
+    while let Some(i) = cursor {
+        out.push(pairs[i]);
+        cursor = back[i];
+    }
+    out.reverse();
+    out
+}
+
+/// Moves deletions before insertions within each run of changes.
+fn reorder(edits: Vec<Edit>) -> Vec<Edit> {
+    let mut out = Vec::with_capacity(edits.len());
+    for run in edits.chunk_by(|x, y| (*x == Edit::Equal) == (*y == Edit::Equal)) {
+        out.extend(run.iter().filter(|e| **e != Edit::Insert));
+        out.extend(run.iter().filter(|e| **e == Edit::Insert));
+    }
+    out
+}
+
+/// Turns token edits into [`DiffOp`]s, merging runs of the same kind into one slice.
+fn merge<'a>(
+    a: &'a str,
+    old: &[&str],
+    b: &'a str,
+    new: &[&str],
+    edits: &[Edit],
+) -> Vec<DiffOp<'a>> {
+    let start = |s: &str, token: &str| token.as_ptr() as usize - s.as_ptr() as usize;
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    for run in edits.chunk_by(|x, y| x == y) {
+        let len = run.len();
+        let op = match run[0] {
+            Edit::Equal | Edit::Delete => {
+                let from = start(a, old[i]);
+                let to = start(a, old[i + len - 1]) + old[i + len - 1].len();
+                let text = &a[from..to];
+                i += len;
+                if run[0] == Edit::Equal {
+                    j += len;
+                    DiffOp::Equal(text)
+                } else {
+                    DiffOp::Delete(text)
+                }
+            }
+            Edit::Insert => {
+                let from = start(b, new[j]);
+                let to = start(b, new[j + len - 1]) + new[j + len - 1].len();
+                j += len;
+                DiffOp::Insert(&b[from..to])
+            }
+        };
+        ops.push(op);
+    }
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_myers_matches_lcs() {
+        // Small alphabets give many competing paths; compare against an LCS table.
+        let mut seed = 0x2545_f491_u32;
+        let mut next = |bound: u32| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed % bound
+        };
+        let tokens = ["a", "b", "c"];
+        for _ in 0..500 {
+            let a: Vec<&str> = (0..next(12)).map(|_| tokens[next(3) as usize]).collect();
+            let b: Vec<&str> = (0..next(12)).map(|_| tokens[next(3) as usize]).collect();
+
+            let mut lcs = vec![vec![0; b.len() + 1]; a.len() + 1];
+            for i in (0..a.len()).rev() {
+                for j in (0..b.len()).rev() {
+                    lcs[i][j] = if a[i] == b[j] {
+                        lcs[i + 1][j + 1] + 1
+                    } else {
+                        lcs[i + 1][j].max(lcs[i][j + 1])
+                    };
+                }
+            }
+
+            let script = edits(&a, &b, DiffAlgorithm::Myers);
+            let (mut i, mut j, mut rebuilt) = (0, 0, Vec::new());
+            for edit in &script {
+                match edit {
+                    Edit::Equal => {
+                        assert_eq!(a[i], b[j]);
+                        rebuilt.push(a[i]);
+                        i += 1;
+                        j += 1;
+                    }
+                    Edit::Delete => i += 1,
+                    Edit::Insert => {
+                        rebuilt.push(b[j]);
+                        j += 1;
+                    }
+                }
+            }
+            assert_eq!((i, rebuilt), (a.len(), b.clone()));
+            let changes = script.iter().filter(|e| **e != Edit::Equal).count();
+            assert_eq!(changes, a.len() + b.len() - 2 * lcs[0][0], "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_myers_large_inputs() {
+        let a: String = (0..5000).map(|i| format!("a{i}\n")).collect();
+        let b: String = (0..5000).map(|i| format!("b{i}\n")).collect();
+        let ops = diff_lines(&a, &b);
+        assert_eq!(ops, vec![DiffOp::Delete(&a), DiffOp::Insert(&b)]);
+    }
+
+    /// Rebuilds the old and new text from the ops.
+    fn sides(ops: &[DiffOp]) -> (String, String) {
+        let (mut old, mut new) = (String::new(), String::new());
+        for op in ops {
+            match op {
+                DiffOp::Equal(t) => {
+                    old.push_str(t);
+                    new.push_str(t);
+                }
+                DiffOp::Delete(t) => old.push_str(t),
+                DiffOp::Insert(t) => new.push_str(t),
+            }
+        }
+        (old, new)
+    }
+
+    #[test]
+    fn test_ops_rebuild_both_sides() {
+        let cases = [
+            ("", ""),
+            ("abc", ""),
+            ("", "abc"),
+            ("a\nb\nc\nd\n", "b\nc\ne\nd\nf"),
+            ("the quick brown fox", "a quick red fox jumps"),
+            ("héllo wörld", "hello world!"),
+        ];
+        for (a, b) in cases {
+            for unit in [DiffUnit::Lines, DiffUnit::Words, DiffUnit::Chars] {
+                for algorithm in [DiffAlgorithm::Myers, DiffAlgorithm::Patience] {
+                    let ops = diff_with(a, b, unit, algorithm);
+                    assert_eq!(sides(&ops), (a.to_string(), b.to_string()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_myers_is_minimal() {
+        let ops = diff_chars("ABCABBA", "CBABAC");
+        let edits: usize = ops
+            .iter()
+            .map(|op| match op {
+                DiffOp::Equal(_) => 0,
+                DiffOp::Delete(t) | DiffOp::Insert(t) => t.len(),
+            })
+            .sum();
+        assert_eq!(edits, 5);
+    }
+
+    #[test]
+    fn test_patience_anchors_on_unique_lines() {
+        let a = "x = 1\n}\n}\ny = 2\n";
+        let b = "y = 2\n}\n}\nx = 1\n";
+        // Myers matches the repeated braces; patience keeps the unique line intact.
+        assert_eq!(
+            diff_lines(a, b),
+            vec![
+                DiffOp::Delete("x = 1\n"),
+                DiffOp::Insert("y = 2\n"),
+                DiffOp::Equal("}\n}\n"),
+                DiffOp::Delete("y = 2\n"),
+                DiffOp::Insert("x = 1\n"),
+            ]
+        );
+        assert_eq!(
+            diff_with(a, b, DiffUnit::Lines, DiffAlgorithm::Patience),
+            vec![
+                DiffOp::Delete("x = 1\n}\n}\n"),
+                DiffOp::Equal("y = 2\n"),
+                DiffOp::Insert("}\n}\nx = 1\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_word_tokens_keep_separators() {
+        let ops = diff_words("a, b, c", "a, x, c");
+        assert_eq!(
+            ops,
+            vec![
+                DiffOp::Equal("a, "),
+                DiffOp::Delete("b"),
+                DiffOp::Insert("x"),
+                DiffOp::Equal(", c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chars_are_graphemes() {
+        let ops = diff_chars("e\u{301}a", "ea");
+        assert_eq!(
+            ops,
+            vec![
+                DiffOp::Delete("e\u{301}"),
+                DiffOp::Insert("e"),
+                DiffOp::Equal("a")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_hunks() {
+        let a: String = (1..=10).map(|n| format!("{n}\n")).collect();
+        let b = a.replace("2\n", "two\n").replace("9\n", "nine\n");
+        assert_eq!(
+            unified_diff(&a, &b, 1),
+            "@@ -1,3 +1,3 @@\n 1\n-2\n+two\n 3\n@@ -8,3 +8,3 @@\n 8\n-9\n+nine\n 10\n"
+        );
+        assert_eq!(
+            unified_diff(&a, &b, 3),
+            "@@ -1,10 +1,10 @@\n 1\n-2\n+two\n 3\n 4\n 5\n 6\n 7\n 8\n-9\n+nine\n 10\n"
+        );
+        assert_eq!(unified_diff(&a, &a, 3), "");
+    }
+
+    #[test]
+    fn test_unified_diff_edges() {
+        assert_eq!(unified_diff("", "a\n", 3), "@@ -0,0 +1 @@\n+a\n");
+        assert_eq!(
+            unified_diff("a", "b", 0),
+            "@@ -1 +1 @@\n-a\n\\ No newline at end of file\n+b\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_renderers() {
+        let ops = diff_words("x = 1", "x = 2");
+        assert_eq!(render_inline(&ops), "x = [-1-]{+2+}");
+        assert_eq!(render_ansi(&ops), "x = \x1b[31m1\x1b[0m\x1b[32m2\x1b[0m");
+    }
+}
@@ -0,0 +1,37 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Returns the number of terminal columns a string occupies.
+///
+/// Wide characters such as CJK ideographs and most emoji take two columns,
+/// and combining marks and zero-width characters take none.
+///
+/// # Example
+/// ```rust
+/// use lo_::display_width;
+///
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("日本"), 4);
+/// assert_eq!(display_width("e\u{301}"), 1);
+///
+/// ```
+pub fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(grapheme_width).sum()
+}
+
+/// Columns taken by a single grapheme cluster.
+pub(crate) fn grapheme_width(g: &str) -> usize {
+    UnicodeWidthStr::width(g)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emoji_and_control() {
+        assert_eq!(display_width("🚀"), 2);
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("a\u{200b}b"), 2);
+    }
+}
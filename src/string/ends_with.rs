@@ -44,13 +44,13 @@ mod tests {
 
     #[test]
     fn test_ends_with() {
-        assert_eq!(ends_with("abc", "b"), false);
-        assert_eq!(ends_with("abc", "c"), true);
+        assert!(!ends_with("abc", "b"));
+        assert!(ends_with("abc", "c"));
     }
 
     #[test]
     fn test_ends_with_position() {
-        assert_eq!(ends_with_position("abc", "b", Some(2)), true);
-        assert_eq!(ends_with_position("abc", "bc", Some(3)), true);
+        assert!(ends_with_position("abc", "b", Some(2)));
+        assert!(ends_with_position("abc", "bc", Some(3)));
     }
 }
@@ -0,0 +1,187 @@
+/// Named character references understood by [`unescape_html`].
+const NAMED_ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{a0}'),
+    ("copy", '©'),
+    ("reg", '®'),
+    ("trade", '™'),
+    ("hellip", '…'),
+    ("mdash", '—'),
+    ("ndash", '–'),
+    ("lsquo", '‘'),
+    ("rsquo", '’'),
+    ("ldquo", '“'),
+    ("rdquo", '”'),
+    ("laquo", '«'),
+    ("raquo", '»'),
+    ("bull", '•'),
+    ("middot", '·'),
+    ("deg", '°'),
+    ("plusmn", '±'),
+    ("times", '×'),
+    ("divide", '÷'),
+    ("sect", '§'),
+    ("para", '¶'),
+    ("cent", '¢'),
+    ("pound", '£'),
+    ("yen", '¥'),
+    ("euro", '€'),
+];
+
+/// Escapes `&`, `<`, `>`, `"` and `'` so the text can be placed in HTML content
+/// or a quoted attribute value.
+///
+/// # Example
+/// ```rust
+/// use lo_::escape_html;
+///
+/// assert_eq!(
+///     escape_html(r#"<a href="x">Tom & Jerry's</a>"#),
+///     "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+/// );
+///
+/// ```
+pub fn escape_html(s: &str) -> String {
+    escape_markup(s, "&#39;")
+}
+
+/// Replaces HTML character references with the characters they stand for.
+///
+/// Handles decimal (`&#39;`) and hexadecimal (`&#x27;`) references and common
+/// named ones (`&amp;`, `&nbsp;`, `&copy;`, …). References must end with `;`;
+/// unknown or malformed ones are left as they are. Numeric references to
+/// invalid code points become U+FFFD.
+///
+/// # Example
+/// ```rust
+/// use lo_::unescape_html;
+///
+/// assert_eq!(unescape_html("Tom &amp; Jerry&#39;s &#x1F600; &copy;"), "Tom & Jerry's 😀 ©");
+/// assert_eq!(unescape_html("AT&T &bogus;"), "AT&T &bogus;");
+///
+/// ```
+pub fn unescape_html(s: &str) -> String {
+    unescape_markup(s, NAMED_ENTITIES)
+}
+
+/// Escapes the five XML special characters, using `&apos;` for `'`.
+///
+/// # Example
+/// ```rust
+/// use lo_::escape_xml;
+///
+/// assert_eq!(escape_xml("<note a='1'>&</note>"), "&lt;note a=&apos;1&apos;&gt;&amp;&lt;/note&gt;");
+///
+/// ```
+pub fn escape_xml(s: &str) -> String {
+    escape_markup(s, "&apos;")
+}
+
+/// Replaces numeric character references and the five predefined XML entities.
+///
+/// # Example
+/// ```rust
+/// use lo_::unescape_xml;
+///
+/// assert_eq!(unescape_xml("&lt;a&gt; &apos;b&apos; &#65; &nbsp;"), "<a> 'b' A &nbsp;");
+///
+/// ```
+pub fn unescape_xml(s: &str) -> String {
+    unescape_markup(s, &NAMED_ENTITIES[..5])
+}
+
+fn escape_markup(s: &str, apostrophe: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str(apostrophe),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn unescape_markup(s: &str, named: &[(&str, char)]) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        match reference(rest, named) {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Decodes the reference at the start of `s` (which begins with `&`), returning
+/// the character and the byte length of the reference.
+fn reference(s: &str, named: &[(&str, char)]) -> Option<(char, usize)> {
+    // The longest references (`&#x10FFFF;`, `&plusmn;`) are far shorter than this.
+    let end = s.bytes().take(32).position(|b| b == b';')?;
+    let body = &s[1..end];
+    let c = if let Some(number) = body.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) if !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                u32::from_str_radix(hex, 16).unwrap_or(u32::MAX)
+            }
+            None if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) => {
+                number.parse().unwrap_or(u32::MAX)
+            }
+            _ => return None,
+        };
+        match code {
+            0 => '\u{fffd}',
+            _ => char::from_u32(code).unwrap_or('\u{fffd}'),
+        }
+    } else {
+        named.iter().find(|(name, _)| *name == body)?.1
+    };
+    Some((c, end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let text = "<p class=\"x\">It's 5 > 3 & 2 < 4</p> ünïcödé";
+        assert_eq!(unescape_html(&escape_html(text)), text);
+        assert_eq!(unescape_xml(&escape_xml(text)), text);
+    }
+
+    #[test]
+    fn test_numeric_references() {
+        assert_eq!(unescape_html("&#60;&#x3C;&#X3c;"), "<<<");
+        assert_eq!(
+            unescape_html("&#0;&#xD800;&#99999999999;"),
+            "\u{fffd}\u{fffd}\u{fffd}"
+        );
+        assert_eq!(unescape_html("&#;&#x;&#12a;"), "&#;&#x;&#12a;");
+    }
+
+    #[test]
+    fn test_malformed_references_kept() {
+        assert_eq!(unescape_html("a & b"), "a & b");
+        assert_eq!(unescape_html("&amp"), "&amp");
+        assert_eq!(unescape_html("&&amp;"), "&&");
+        assert_eq!(unescape_xml("&copy;"), "&copy;");
+    }
+}
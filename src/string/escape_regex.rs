@@ -0,0 +1,31 @@
+/// Escapes all regular expression metacharacters so the string matches literally.
+///
+/// This is Lodash's `escapeRegExp`, using the same escaping as the `regex` crate.
+///
+/// # Example
+/// ```rust
+/// use lo_::escape_regex;
+///
+/// assert_eq!(escape_regex("1.5*(x+y)?"), r"1\.5\*\(x\+y\)\?");
+///
+/// let re = regex::Regex::new(&escape_regex("[lo_](https://x.io)")).unwrap();
+/// assert!(re.is_match("see [lo_](https://x.io)"));
+///
+/// ```
+pub fn escape_regex(s: &str) -> String {
+    regex::escape(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+
+    #[test]
+    fn test_matches_literally() {
+        let literal = r"^$.|?*+()[]{}\ -#&~";
+        let re = Regex::new(&format!("^{}$", escape_regex(literal))).unwrap();
+        assert!(re.is_match(literal));
+        assert!(!re.is_match("x"));
+    }
+}
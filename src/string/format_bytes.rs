@@ -0,0 +1,75 @@
+use crate::string::format_number::{scale_down, short_decimal};
+use crate::string::ByteSize;
+use std::fmt;
+
+/// The unit system used by [`format_bytes`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ByteUnits {
+    /// Powers of 1000: `kB`, `MB`, `GB`, …
+    Si,
+    /// Powers of 1024: `KiB`, `MiB`, `GiB`, …
+    #[default]
+    Iec,
+}
+
+const SI: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+const IEC: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Formats a number of bytes with the largest fitting unit and at most one decimal.
+///
+/// The result parses back (rounded) as a [`ByteSize`] with [`parse_lenient`](crate::parse_lenient).
+///
+/// # Example
+/// ```rust
+/// use lo_::{format_bytes, ByteUnits};
+///
+/// assert_eq!(format_bytes(512, ByteUnits::Si), "512 B");
+/// assert_eq!(format_bytes(1536, ByteUnits::Iec), "1.5 KiB");
+/// assert_eq!(format_bytes(2_000_000_000, ByteUnits::Si), "2 GB");
+///
+/// ```
+pub fn format_bytes(bytes: u64, units: ByteUnits) -> String {
+    let (base, names) = match units {
+        ByteUnits::Si => (1000.0, SI),
+        ByteUnits::Iec => (1024.0, IEC),
+    };
+    let (scaled, power) = scale_down(bytes as f64, base, names.len() - 1);
+    format!("{} {}", short_decimal(scaled), names[power])
+}
+
+impl fmt::Display for ByteSize {
+    /// Formats the size with [`ByteUnits::Iec`] units.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_bytes(self.0, ByteUnits::Iec))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::parse_lenient;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0, ByteUnits::Si), "0 B");
+        assert_eq!(format_bytes(999, ByteUnits::Si), "999 B");
+        assert_eq!(format_bytes(1000, ByteUnits::Si), "1 kB");
+        assert_eq!(format_bytes(1023, ByteUnits::Iec), "1023 B");
+        assert_eq!(format_bytes(10 * 1024 * 1024, ByteUnits::Iec), "10 MiB");
+        assert_eq!(format_bytes(1_048_575, ByteUnits::Iec), "1 MiB");
+        assert_eq!(format_bytes(u64::MAX, ByteUnits::Iec), "16 EiB");
+        assert_eq!(ByteSize(1536).to_string(), "1.5 KiB");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for bytes in [0, 512, 1536, 10 * 1024 * 1024, 3 << 30] {
+            let formatted = format_bytes(bytes, ByteUnits::Iec);
+            assert_eq!(parse_lenient::<ByteSize>(&formatted), Ok(ByteSize(bytes)));
+        }
+        for bytes in [999, 1500, 2_000_000_000, 7_300_000_000_000] {
+            let formatted = format_bytes(bytes, ByteUnits::Si);
+            assert_eq!(parse_lenient::<ByteSize>(&formatted), Ok(ByteSize(bytes)));
+        }
+    }
+}
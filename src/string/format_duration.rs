@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+/// The output style of [`format_duration_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// `1h 2m 3s`
+    #[default]
+    Short,
+    /// `1h2m3s`
+    Compact,
+    /// `1 hour 2 minutes 3 seconds`
+    Long,
+}
+
+/// (nanoseconds, short name, long name) of each unit, largest first.
+const UNITS: [(u128, &str, &str); 7] = [
+    (86_400_000_000_000, "d", "day"),
+    (3_600_000_000_000, "h", "hour"),
+    (60_000_000_000, "m", "minute"),
+    (1_000_000_000, "s", "second"),
+    (1_000_000, "ms", "millisecond"),
+    (1_000, "µs", "microsecond"),
+    (1, "ns", "nanosecond"),
+];
+
+/// Formats a duration as its non-zero units, e.g. `1h 2m 3s`.
+///
+/// The result parses back with [`parse_lenient`](crate::parse_lenient).
+///
+/// # Example
+/// ```rust
+/// use lo_::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(3723)), "1h 2m 3s");
+/// assert_eq!(format_duration(Duration::from_millis(1500)), "1s 500ms");
+/// assert_eq!(format_duration(Duration::ZERO), "0s");
+///
+/// ```
+pub fn format_duration(d: Duration) -> String {
+    format_duration_with(d, DurationStyle::Short)
+}
+
+/// Formats a duration in the given [`DurationStyle`].
+///
+/// # Example
+/// ```rust
+/// use lo_::{format_duration_with, DurationStyle};
+/// use std::time::Duration;
+///
+/// let d = Duration::from_secs(90_061);
+/// assert_eq!(format_duration_with(d, DurationStyle::Compact), "1d1h1m1s");
+/// assert_eq!(
+///     format_duration_with(d, DurationStyle::Long),
+///     "1 day 1 hour 1 minute 1 second"
+/// );
+///
+/// ```
+pub fn format_duration_with(d: Duration, style: DurationStyle) -> String {
+    let mut rest = d.as_nanos();
+    let mut parts = Vec::new();
+
+    for (nanos, short, long) in UNITS {
+        let count = rest / nanos;
+        rest %= nanos;
+        if count > 0 || (parts.is_empty() && rest == 0 && short == "s") {
+            parts.push(match style {
+                DurationStyle::Long if count == 1 => format!("{count} {long}"),
+                DurationStyle::Long => format!("{count} {long}s"),
+                _ => format!("{count}{short}"),
+            });
+        }
+        if rest == 0 && !parts.is_empty() {
+            break;
+        }
+    }
+
+    let separator = if style == DurationStyle::Compact {
+        ""
+    } else {
+        " "
+    };
+    parts.join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::parse_lenient;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(59)), "59s");
+        assert_eq!(format_duration(Duration::from_secs(3600)), "1h");
+        assert_eq!(format_duration(Duration::from_secs(86_400 + 5)), "1d 5s");
+        assert_eq!(format_duration(Duration::from_nanos(1_001)), "1µs 1ns");
+    }
+
+    #[test]
+    fn test_styles() {
+        let d = Duration::from_millis(7_322_250);
+        assert_eq!(
+            format_duration_with(d, DurationStyle::Compact),
+            "2h2m2s250ms"
+        );
+        assert_eq!(
+            format_duration_with(d, DurationStyle::Long),
+            "2 hours 2 minutes 2 seconds 250 milliseconds"
+        );
+        assert_eq!(
+            format_duration_with(Duration::ZERO, DurationStyle::Long),
+            "0 seconds"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let durations = [
+            Duration::ZERO,
+            Duration::from_secs(3723),
+            Duration::from_millis(1500),
+            Duration::new(1_000_000, 123_456_789),
+        ];
+        for d in durations {
+            for style in [
+                DurationStyle::Short,
+                DurationStyle::Compact,
+                DurationStyle::Long,
+            ] {
+                let formatted = format_duration_with(d, style);
+                assert_eq!(parse_lenient::<Duration>(&formatted), Ok(d), "{formatted}");
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+/// Formats a number with `,` thousands separators and exactly `decimals` decimal places.
+///
+/// The result parses back with [`parse_lenient`](crate::parse_lenient).
+///
+/// # Example
+/// ```rust
+/// use lo_::format_number;
+///
+/// assert_eq!(format_number(1234567.891, 2), "1,234,567.89");
+/// assert_eq!(format_number(-1234.0, 0), "-1,234");
+/// assert_eq!(format_number(0.5, 3), "0.500");
+///
+/// ```
+pub fn format_number(n: f64, decimals: usize) -> String {
+    if !n.is_finite() {
+        return n.to_string();
+    }
+
+    let formatted = format!("{:.*}", decimals, n.abs());
+    let (whole, fraction) = match formatted.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut out = String::with_capacity(formatted.len() + whole.len() / 3 + 1);
+    let is_zero = formatted.chars().all(|c| c == '0' || c == '.');
+    if n.is_sign_negative() && !is_zero {
+        out.push('-');
+    }
+    for (i, c) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    if let Some(fraction) = fraction {
+        out.push('.');
+        out.push_str(fraction);
+    }
+    out
+}
+
+/// Formats `n` with at most one decimal, dropping a trailing `.0`.
+pub(crate) fn short_decimal(n: f64) -> String {
+    let formatted = format!("{n:.1}");
+    formatted
+        .strip_suffix(".0")
+        .map(str::to_string)
+        .unwrap_or(formatted)
+}
+
+/// Divides `value` by `base` until it is below `base` (after rounding to one
+/// decimal), returning the scaled value and how many times it was divided.
+pub(crate) fn scale_down(value: f64, base: f64, max_power: usize) -> (f64, usize) {
+    let mut scaled = value;
+    let mut power = 0;
+    while power < max_power && (scaled * 10.0).round() / 10.0 >= base {
+        scaled /= base;
+        power += 1;
+    }
+    (scaled, power)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::parse_lenient;
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(0.0, 0), "0");
+        assert_eq!(format_number(999.0, 0), "999");
+        assert_eq!(format_number(1000.0, 0), "1,000");
+        assert_eq!(format_number(1234567.0, 1), "1,234,567.0");
+        assert_eq!(format_number(999.999, 2), "1,000.00");
+        assert_eq!(format_number(-0.001, 2), "0.00");
+        assert_eq!(format_number(f64::NAN, 2), "NaN");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for n in [0.0, 12.5, -1234.75, 9876543.21] {
+            assert_eq!(parse_lenient::<f64>(&format_number(n, 2)), Ok(n));
+        }
+    }
+}
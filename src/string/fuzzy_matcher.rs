@@ -0,0 +1,269 @@
+use crate::string::{words_iter, WordsMode};
+
+const MATCH_SCORE: i64 = 16;
+const CONSECUTIVE_BONUS: i64 = 15;
+const BOUNDARY_BONUS: i64 = 10;
+const GAP_START_PENALTY: i64 = 3;
+
+/// A fuzzy match of a pattern in a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyScore {
+    /// Higher is better.
+    pub score: i64,
+    /// Char indices of the matched characters, in increasing order.
+    pub positions: Vec<usize>,
+}
+
+/// An item of a collection ranked by [`FuzzyMatcher::rank`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyMatch<'a, T> {
+    /// The matched item.
+    pub item: &'a T,
+    /// Index of the item in the ranked slice.
+    pub index: usize,
+    /// Higher is better.
+    pub score: i64,
+    /// Char indices of the matched characters in the item's key.
+    pub positions: Vec<usize>,
+}
+
+/// Subsequence fuzzy matching in the style of fzf and Sublime Text.
+///
+/// Every character of the pattern must appear in the text, in order. Runs of
+/// consecutive characters and matches at the start of a word score higher,
+/// and gaps between matches cost a little. Word starts come from [`words_iter`]
+/// in [`WordsMode::Lodash`], so `fb` matches the humps of `fooBar` and `foo_bar`.
+///
+/// By default matching is smart-case: case-insensitive unless the pattern
+/// contains an uppercase letter.
+///
+/// # Example
+/// ```rust
+/// use lo_::FuzzyMatcher;
+///
+/// let files = ["src/main.rs", "src/string/mod.rs", "README.md", "src/lib.rs"];
+/// let matcher = FuzzyMatcher::new();
+///
+/// let ranked: Vec<&str> = matcher.rank("srm", &files, |f| *f).iter().map(|m| *m.item).collect();
+/// assert_eq!(ranked, vec!["src/main.rs", "src/string/mod.rs"]);
+///
+/// let best = matcher.fuzzy_match("srm", "src/main.rs").unwrap();
+/// assert_eq!(best.positions, vec![0, 1, 4]);
+///
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FuzzyMatcher {
+    case_sensitive: Option<bool>,
+}
+
+impl FuzzyMatcher {
+    /// Creates a smart-case matcher.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forces case-sensitive (`true`) or case-insensitive (`false`) matching
+    /// instead of smart case.
+    pub fn case_sensitive(mut self, enabled: bool) -> Self {
+        self.case_sensitive = Some(enabled);
+        self
+    }
+
+    /// Scores `text` against `pattern`, or returns `None` if it does not match.
+    ///
+    /// The positions are those of the best-scoring alignment. An empty pattern
+    /// matches everything with a score of `0`.
+    pub fn fuzzy_match(&self, pattern: &str, text: &str) -> Option<FuzzyScore> {
+        let case_sensitive = self
+            .case_sensitive
+            .unwrap_or_else(|| pattern.chars().any(char::is_uppercase));
+        let fold = |c: char| {
+            if case_sensitive {
+                c
+            } else {
+                c.to_lowercase().next().unwrap_or(c)
+            }
+        };
+
+        let pattern: Vec<char> = pattern.chars().map(fold).collect();
+        let chars: Vec<char> = text.chars().map(fold).collect();
+        if pattern.is_empty() {
+            return Some(FuzzyScore {
+                score: 0,
+                positions: Vec::new(),
+            });
+        }
+        if pattern.len() > chars.len() {
+            return None;
+        }
+
+        let boundaries = word_starts(text, chars.len());
+        let (n, m) = (pattern.len(), chars.len());
+        // scores[i * m + j]: best score with pattern[i] matched at text[j]; from: where pattern[i - 1] matched.
+        let mut scores: Vec<Option<i64>> = vec![None; n * m];
+        let mut from = vec![0usize; n * m];
+
+        for (i, p) in pattern.iter().enumerate() {
+            // Best (score + index) of the previous row at least two chars back, for gapped matches.
+            let mut gapped: Option<(i64, usize)> = None;
+            for j in 0..m {
+                if i > 0 && j >= 2 {
+                    if let Some(s) = scores[(i - 1) * m + j - 2] {
+                        let candidate = s + (j - 2) as i64;
+                        if gapped.is_none_or(|(best, _)| candidate > best) {
+                            gapped = Some((candidate, j - 2));
+                        }
+                    }
+                }
+                if chars[j] != *p {
+                    continue;
+                }
+
+                let bonus = MATCH_SCORE + if boundaries[j] { BOUNDARY_BONUS } else { 0 };
+                let cell = i * m + j;
+                if i == 0 {
+                    scores[cell] = Some(bonus);
+                    continue;
+                }
+
+                let consecutive = j
+                    .checked_sub(1)
+                    .and_then(|k| scores[(i - 1) * m + k].map(|s| (s + CONSECUTIVE_BONUS, k)));
+                let gap = gapped.map(|(best, k)| (best - j as i64 - GAP_START_PENALTY + 2, k));
+                let best = match (consecutive, gap) {
+                    (Some(c), Some(g)) if g.0 > c.0 => Some(g),
+                    (Some(c), _) => Some(c),
+                    (None, g) => g,
+                };
+                if let Some((score, k)) = best {
+                    scores[cell] = Some(score + bonus);
+                    from[cell] = k;
+                }
+            }
+        }
+
+        let last = (n - 1) * m;
+        let (mut j, score) = (0..m)
+            .filter_map(|j| scores[last + j].map(|s| (j, s)))
+            .fold(None, |best: Option<(usize, i64)>, (j, s)| match best {
+                Some((_, b)) if b >= s => best,
+                _ => Some((j, s)),
+            })?;
+
+        let mut positions = vec![0; n];
+        for i in (0..n).rev() {
+            positions[i] = j;
+            j = from[i * m + j];
+        }
+        Some(FuzzyScore { score, positions })
+    }
+
+    /// Ranks the items whose key matches `pattern`, best first.
+    ///
+    /// Items with equal scores keep their order in `items`.
+    pub fn rank<'a, T, K, F>(&self, pattern: &str, items: &'a [T], key: F) -> Vec<FuzzyMatch<'a, T>>
+    where
+        K: AsRef<str>,
+        F: Fn(&T) -> K,
+    {
+        let mut matches: Vec<FuzzyMatch<'a, T>> = items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                let found = self.fuzzy_match(pattern, key(item).as_ref())?;
+                Some(FuzzyMatch {
+                    item,
+                    index,
+                    score: found.score,
+                    positions: found.positions,
+                })
+            })
+            .collect();
+        matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+        matches
+    }
+}
+
+/// Marks the char indices where a word starts.
+fn word_starts(text: &str, len: usize) -> Vec<bool> {
+    let mut starts = vec![false; len];
+    let mut chars = text.char_indices().enumerate();
+    for word in words_iter(text, WordsMode::Lodash) {
+        let offset = word.as_ptr() as usize - text.as_ptr() as usize;
+        for (index, (byte, _)) in chars.by_ref() {
+            if byte == offset {
+                starts[index] = true;
+                break;
+            }
+        }
+    }
+    starts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_required() {
+        let matcher = FuzzyMatcher::new();
+        assert!(matcher.fuzzy_match("abc", "a_b_c").is_some());
+        assert!(matcher.fuzzy_match("acb", "abc").is_none());
+        assert!(matcher.fuzzy_match("abcd", "abc").is_none());
+        assert_eq!(matcher.fuzzy_match("", "abc").unwrap().score, 0);
+    }
+
+    #[test]
+    fn test_prefers_boundaries_and_runs() {
+        let matcher = FuzzyMatcher::new();
+        let hump = matcher.fuzzy_match("fb", "fooBar").unwrap();
+        assert_eq!(hump.positions, vec![0, 3]);
+
+        // "bar" as a run beats scattered letters.
+        let found = matcher.fuzzy_match("bar", "b_a_r_bar").unwrap();
+        assert_eq!(found.positions, vec![6, 7, 8]);
+
+        let boundary = matcher.fuzzy_match("gc", "git_commit").unwrap();
+        let inner = matcher.fuzzy_match("gc", "gxxcxx").unwrap();
+        assert!(boundary.score > inner.score);
+    }
+
+    #[test]
+    fn test_smart_case() {
+        let matcher = FuzzyMatcher::new();
+        assert!(matcher.fuzzy_match("readme", "README.md").is_some());
+        assert!(matcher.fuzzy_match("ReadMe", "README.md").is_none());
+        assert!(matcher
+            .case_sensitive(false)
+            .fuzzy_match("ReadMe", "README.md")
+            .is_some());
+        assert!(matcher
+            .case_sensitive(true)
+            .fuzzy_match("readme", "README.md")
+            .is_none());
+    }
+
+    #[test]
+    fn test_positions_are_char_indices() {
+        let found = FuzzyMatcher::new().fuzzy_match("ße", "Straße").unwrap();
+        assert_eq!(found.positions, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_rank_is_stable() {
+        struct User {
+            name: &'static str,
+        }
+        let users = [
+            User { name: "bob" },
+            User { name: "alice" },
+            User { name: "bob" },
+            User { name: "bobby" },
+        ];
+        let ranked = FuzzyMatcher::new().rank("bob", &users, |u| u.name);
+        let indices: Vec<usize> = ranked.iter().map(|m| m.index).collect();
+        assert_eq!(indices, vec![0, 2, 3]);
+        assert_eq!(ranked[0].item.name, "bob");
+        assert_eq!(ranked[0].score, ranked[1].score);
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+/// Finds hyphenation points with Liang's algorithm (the one used by TeX),
+/// driven by a user-supplied pattern dictionary.
+///
+/// Patterns use the TeX notation: letters with digits between them, where an
+/// odd digit allows a break and an even digit forbids it, and `.` marks a word
+/// boundary (e.g. `hy3ph`, `.ach4`, `1tio`).
+///
+/// # Example
+/// ```rust
+/// use lo_::Hyphenator;
+///
+/// let hyphenator = Hyphenator::new([
+///     "hy3ph", "he2n", "hena4", "hen5at", "1na", "n2at", "1tio", "2io", "o2n",
+/// ]);
+/// assert_eq!(hyphenator.hyphenate("hyphenation"), vec!["hy", "phen", "ation"]);
+///
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hyphenator {
+    patterns: HashMap<String, Vec<u8>>,
+    left_min: usize,
+    right_min: usize,
+}
+
+impl Default for Hyphenator {
+    /// A hyphenator without patterns and the default minimum lengths.
+    fn default() -> Self {
+        Self::new(std::iter::empty::<&str>())
+    }
+}
+
+impl Hyphenator {
+    /// Creates a hyphenator from TeX-style patterns.
+    ///
+    /// By default at least 2 letters are kept before a break and 3 after it.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut hyphenator = Self {
+            patterns: HashMap::new(),
+            left_min: 2,
+            right_min: 3,
+        };
+        for pattern in patterns {
+            hyphenator.add_pattern(pattern.as_ref());
+        }
+        hyphenator
+    }
+
+    /// Sets the minimum number of letters kept before and after a break.
+    pub fn min_lengths(mut self, left_min: usize, right_min: usize) -> Self {
+        self.left_min = left_min.max(1);
+        self.right_min = right_min.max(1);
+        self
+    }
+
+    /// Adds a single TeX-style pattern.
+    pub fn add_pattern(&mut self, pattern: &str) {
+        let mut letters = String::new();
+        let mut values = vec![0u8];
+
+        for c in pattern.chars() {
+            match c.to_digit(10) {
+                Some(d) => *values.last_mut().unwrap() = d as u8,
+                None => {
+                    letters.extend(c.to_lowercase());
+                    values.push(0);
+                }
+            }
+        }
+
+        if !letters.is_empty() {
+            self.patterns.insert(letters, values);
+        }
+    }
+
+    /// Returns the char positions at which `word` may be broken.
+    /// A position `i` allows a break between the `i - 1`th and `i`th char.
+    pub fn break_points(&self, word: &str) -> Vec<usize> {
+        let chars: Vec<char> = std::iter::once('.')
+            .chain(word.chars().flat_map(char::to_lowercase))
+            .chain(std::iter::once('.'))
+            .collect();
+        let len = word.chars().count();
+        // Never break before the first char or after the last one.
+        let (left_min, right_min) = (self.left_min.max(1), self.right_min.max(1));
+        if chars.len() != len + 2 || len < left_min + right_min {
+            return Vec::new();
+        }
+
+        let mut points = vec![0u8; chars.len() + 1];
+        for start in 0..chars.len() {
+            let mut key = String::new();
+            for (end, c) in chars.iter().enumerate().skip(start) {
+                key.push(*c);
+                if let Some(values) = self.patterns.get(&key) {
+                    for (k, v) in values.iter().enumerate() {
+                        points[start + k] = points[start + k].max(*v);
+                    }
+                }
+                if end - start > 32 {
+                    break;
+                }
+            }
+        }
+
+        (left_min..=len - right_min)
+            .filter(|&i| points[i + 1] % 2 == 1)
+            .collect()
+    }
+
+    /// Splits `word` into its hyphenatable parts.
+    pub fn hyphenate<'a>(&self, word: &'a str) -> Vec<&'a str> {
+        let offsets: Vec<usize> = word.char_indices().map(|(i, _)| i).collect();
+        let mut parts = Vec::new();
+        let mut last = 0;
+        for point in self.break_points(word) {
+            let byte = offsets[point];
+            parts.push(&word[last..byte]);
+            last = byte;
+        }
+        parts.push(&word[last..]);
+        parts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn liang() -> Hyphenator {
+        Hyphenator::new([
+            "hy3ph", "he2n", "hena4", "hen5at", "1na", "n2at", "1tio", "2io", "o2n",
+        ])
+    }
+
+    #[test]
+    fn test_break_points() {
+        assert_eq!(liang().break_points("hyphenation"), vec![2, 6]);
+        assert_eq!(liang().break_points("Hyphenation"), vec![2, 6]);
+    }
+
+    #[test]
+    fn test_hyphenate() {
+        assert_eq!(
+            liang().hyphenate("hyphenation"),
+            vec!["hy", "phen", "ation"]
+        );
+        assert_eq!(liang().hyphenate("rust"), vec!["rust"]);
+    }
+
+    #[test]
+    fn test_min_lengths() {
+        let hyphenator = liang().min_lengths(3, 3);
+        assert_eq!(hyphenator.break_points("hyphenation"), vec![6]);
+    }
+
+    #[test]
+    fn test_short_and_empty_words() {
+        assert!(liang().break_points("").is_empty());
+        assert!(liang().break_points("hyp").is_empty());
+    }
+
+    #[test]
+    fn test_never_breaks_at_word_edges() {
+        let mut hyphenator = Hyphenator::default();
+        assert_eq!((hyphenator.left_min, hyphenator.right_min), (2, 3));
+        hyphenator.add_pattern("b1");
+        assert_eq!(hyphenator.hyphenate("ab"), vec!["ab"]);
+
+        let mut hyphenator = Hyphenator {
+            left_min: 0,
+            right_min: 0,
+            ..Hyphenator::default()
+        };
+        hyphenator.add_pattern("b1");
+        hyphenator.add_pattern("1a");
+        assert_eq!(hyphenator.hyphenate("ab"), vec!["ab"]);
+        assert_eq!(hyphenator.hyphenate("aab"), vec!["a", "ab"]);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::string::normalize_line_endings::split_line_endings;
+
+/// Adds `prefix` to the start of every line for which `predicate` returns `true`.
+///
+/// The predicate receives each line without its line ending. Line endings are
+/// kept as they are.
+///
+/// # Example
+/// ```rust
+/// use lo_::indent;
+///
+/// let code = "if x {\n\n    y();\n}";
+/// assert_eq!(indent(code, "    ", |_| true), "    if x {\n    \n        y();\n    }");
+/// assert_eq!(
+///     indent(code, "    ", |line| !line.trim().is_empty()),
+///     "    if x {\n\n        y();\n    }"
+/// );
+///
+/// ```
+pub fn indent<F>(s: &str, prefix: &str, predicate: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    let mut out = String::with_capacity(s.len());
+    for (line, end) in split_line_endings(s) {
+        if predicate(line) {
+            out.push_str(prefix);
+        }
+        out.push_str(line);
+        out.push_str(end);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trips_with_dedent() {
+        let text = "a\r\n  b\nc";
+        let indented = indent(text, "> ", |_| true);
+        assert_eq!(indented, "> a\r\n>   b\n> c");
+        assert_eq!(crate::string::dedent(&indent(text, "  ", |_| true)), text);
+    }
+}
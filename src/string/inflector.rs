@@ -0,0 +1,478 @@
+use crate::string::{camel_case, snake_case, upper_first};
+use regex::Regex;
+use std::sync::OnceLock;
+
+// Rails' English inflections, in the order Rails defines them; later rules win.
+const PLURALS: &[(&str, &str)] = &[
+    (r"$", "s"),
+    (r"s$", "s"),
+    (r"^(ax|test)is$", "${1}es"),
+    (r"(octop|vir)us$", "${1}i"),
+    (r"(octop|vir)i$", "${1}i"),
+    (r"(alias|status)$", "${1}es"),
+    (r"(bu)s$", "${1}ses"),
+    (r"(buffal|tomat)o$", "${1}oes"),
+    (r"([ti])um$", "${1}a"),
+    (r"([ti])a$", "${1}a"),
+    (r"sis$", "ses"),
+    (r"(?:([^f])fe|([lr])f)$", "${1}${2}ves"),
+    (r"(hive)$", "${1}s"),
+    (r"([^aeiouy]|qu)y$", "${1}ies"),
+    (r"(x|ch|ss|sh)$", "${1}es"),
+    (r"(matr|vert|ind)(?:ix|ex)$", "${1}ices"),
+    (r"^(m|l)ouse$", "${1}ice"),
+    (r"^(m|l)ice$", "${1}ice"),
+    (r"^(ox)$", "${1}en"),
+    (r"^(oxen)$", "${1}"),
+    (r"(quiz)$", "${1}zes"),
+];
+
+const SINGULARS: &[(&str, &str)] = &[
+    (r"s$", ""),
+    (r"(ss)$", "${1}"),
+    (r"(n)ews$", "${1}ews"),
+    (r"([ti])a$", "${1}um"),
+    (
+        r"((a)naly|(b)a|(d)iagno|(p)arenthe|(p)rogno|(s)ynop|(t)he)(sis|ses)$",
+        "${1}sis",
+    ),
+    (r"(^analy)(sis|ses)$", "${1}sis"),
+    (r"([^f])ves$", "${1}fe"),
+    (r"(hive)s$", "${1}"),
+    (r"(tive)s$", "${1}"),
+    (r"([lr])ves$", "${1}f"),
+    (r"([^aeiouy]|qu)ies$", "${1}y"),
+    (r"(s)eries$", "${1}eries"),
+    (r"(m)ovies$", "${1}ovie"),
+    (r"(x|ch|ss|sh)es$", "${1}"),
+    (r"^(m|l)ice$", "${1}ouse"),
+    (r"(bus)(es)?$", "${1}"),
+    (r"(o)es$", "${1}"),
+    (r"(shoe)s$", "${1}"),
+    (r"(cris|test)(is|es)$", "${1}is"),
+    (r"^(a)x[ie]s$", "${1}xis"),
+    (r"(octop|vir)(us|i)$", "${1}us"),
+    (r"(alias|status)(es)?$", "${1}"),
+    (r"^(ox)en", "${1}"),
+    (r"(vert|ind)ices$", "${1}ex"),
+    (r"(matr)ices$", "${1}ix"),
+    (r"(quiz)zes$", "${1}"),
+    (r"(database)s$", "${1}"),
+];
+
+const IRREGULARS: &[(&str, &str)] = &[
+    ("person", "people"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("child", "children"),
+    ("foot", "feet"),
+    ("tooth", "teeth"),
+    ("goose", "geese"),
+    ("sex", "sexes"),
+    ("move", "moves"),
+    ("zombie", "zombies"),
+];
+
+const UNCOUNTABLES: &[&str] = &[
+    "equipment",
+    "information",
+    "rice",
+    "money",
+    "species",
+    "series",
+    "fish",
+    "sheep",
+    "deer",
+    "jeans",
+    "police",
+    "news",
+    "metadata",
+];
+
+/// Rails-style English inflection: plurals, singulars and the naming helpers
+/// built on them.
+///
+/// [`Inflector::english`] (also the [`Default`]) holds the English rule set
+/// with irregular and uncountable words; extend it with your own rules, which
+/// take precedence over the built-in ones. Only the last word of the input is
+/// inflected (`line_item`, `LineItem` and `line item` all become `…items`),
+/// and its case is kept: `Person` gives `People` and `PERSON` gives `PEOPLE`.
+///
+/// The free functions [`pluralize`], [`singularize`], [`humanize`],
+/// [`tableize`], [`classify`] and [`foreign_key`] use the English rule set.
+///
+/// # Example
+/// ```rust
+/// use lo_::Inflector;
+/// use regex::Regex;
+///
+/// let inflector = Inflector::english()
+///     .irregular("cactus", "cacti")
+///     .uncountable("feedback")
+///     .plural(Regex::new("(?i)(ind)ex$").unwrap(), "${1}exes");
+///
+/// assert_eq!(inflector.pluralize("Cactus"), "Cacti");
+/// assert_eq!(inflector.pluralize("feedback"), "feedback");
+/// assert_eq!(inflector.pluralize("search_index"), "search_indexes");
+/// assert_eq!(inflector.singularize("cacti"), "cactus");
+///
+/// ```
+#[derive(Clone, Debug)]
+pub struct Inflector {
+    plurals: Vec<(Regex, String)>,
+    singulars: Vec<(Regex, String)>,
+    irregulars: Vec<(String, String)>,
+    uncountables: Vec<String>,
+}
+
+impl Default for Inflector {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl Inflector {
+    /// Creates an inflector without any rules.
+    pub fn new() -> Self {
+        Inflector {
+            plurals: Vec::new(),
+            singulars: Vec::new(),
+            irregulars: Vec::new(),
+            uncountables: Vec::new(),
+        }
+    }
+
+    /// Creates an inflector with the English rule set.
+    pub fn english() -> Self {
+        let compile = |rules: &[(&str, &str)]| {
+            rules
+                .iter()
+                .map(|(pattern, replacement)| {
+                    (Regex::new(pattern).unwrap(), replacement.to_string())
+                })
+                .collect()
+        };
+        Inflector {
+            plurals: compile(PLURALS),
+            singulars: compile(SINGULARS),
+            irregulars: IRREGULARS
+                .iter()
+                .map(|(one, many)| (one.to_string(), many.to_string()))
+                .collect(),
+            uncountables: UNCOUNTABLES.iter().map(|w| w.to_string()).collect(),
+        }
+    }
+
+    /// Adds a pluralization rule. Rules see the lowercased last word, and
+    /// `replacement` may use `$1`-style group references.
+    pub fn plural(mut self, rule: Regex, replacement: &str) -> Self {
+        self.plurals.push((rule, replacement.to_string()));
+        self
+    }
+
+    /// Adds a singularization rule, like [`Inflector::plural`].
+    pub fn singular(mut self, rule: Regex, replacement: &str) -> Self {
+        self.singulars.push((rule, replacement.to_string()));
+        self
+    }
+
+    /// Adds a word whose plural does not follow the rules.
+    pub fn irregular(mut self, singular: &str, plural: &str) -> Self {
+        self.irregulars
+            .push((singular.to_lowercase(), plural.to_lowercase()));
+        self
+    }
+
+    /// Adds a word that is the same in singular and plural.
+    pub fn uncountable(mut self, word: &str) -> Self {
+        self.uncountables.push(word.to_lowercase());
+        self
+    }
+
+    /// Returns the plural form of the last word of `s`.
+    pub fn pluralize(&self, s: &str) -> String {
+        self.inflect(s, &self.plurals, true)
+    }
+
+    /// Returns the singular form of the last word of `s`.
+    pub fn singularize(&self, s: &str) -> String {
+        self.inflect(s, &self.singulars, false)
+    }
+
+    /// Turns an identifier into a label: `employee_salary` gives
+    /// `Employee salary` and a trailing `_id` is dropped.
+    pub fn humanize(&self, s: &str) -> String {
+        let snake = snake_case(s);
+        let mut words: Vec<&str> = snake.split('_').filter(|w| !w.is_empty()).collect();
+        if words.len() > 1 && words.last() == Some(&"id") {
+            words.pop();
+        }
+        upper_first(&words.join(" "))
+    }
+
+    /// Turns a type name into a table name: `LineItem` gives `line_items`.
+    pub fn tableize(&self, s: &str) -> String {
+        self.pluralize(&snake_case(s))
+    }
+
+    /// Turns a table name into a type name: `line_items` gives `LineItem`.
+    /// Anything up to the last `.` (a schema name) is dropped.
+    pub fn classify(&self, s: &str) -> String {
+        let table = s.rsplit('.').next().unwrap_or(s);
+        upper_first(&camel_case(&self.singularize(table)))
+    }
+
+    /// Turns a type name into a foreign key column: `Admin::LineItem` gives
+    /// `line_item_id`.
+    pub fn foreign_key(&self, s: &str) -> String {
+        let name = s.rsplit("::").next().unwrap_or(s);
+        format!("{}_id", snake_case(name))
+    }
+
+    fn inflect(&self, s: &str, rules: &[(Regex, String)], to_plural: bool) -> String {
+        let (head, word) = split_last_word(s);
+        let lower = word.to_lowercase();
+        if lower.is_empty() || self.uncountables.contains(&lower) {
+            return s.to_string();
+        }
+
+        let irregular = self.irregulars.iter().rev().find_map(|(one, many)| {
+            let to = if to_plural { many } else { one };
+            (lower == *one || lower == *many).then(|| to.clone())
+        });
+        let inflected = irregular.unwrap_or_else(|| {
+            rules
+                .iter()
+                .rev()
+                .find(|(rule, _)| rule.is_match(&lower))
+                .map_or_else(
+                    || lower.clone(),
+                    |(rule, replacement)| rule.replace(&lower, replacement).into_owned(),
+                )
+        });
+        format!("{head}{}", match_case(word, &inflected))
+    }
+}
+
+/// Splits off the last word: after the last non-alphanumeric character, or
+/// the last camel-case hump.
+fn split_last_word(s: &str) -> (&str, &str) {
+    let start = s
+        .char_indices()
+        .rfind(|(_, c)| !c.is_alphanumeric())
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let mut hump = start;
+    let mut prev_lower = false;
+    for (i, c) in s[start..].char_indices() {
+        if c.is_uppercase() && prev_lower {
+            hump = start + i;
+        }
+        prev_lower = c.is_lowercase() || c.is_numeric();
+    }
+    s.split_at(hump)
+}
+
+/// Applies the case of `original` to the lowercase `inflected` word.
+fn match_case(original: &str, inflected: &str) -> String {
+    let has_lower = original.chars().any(char::is_lowercase);
+    if !has_lower && original.chars().count() > 1 {
+        return inflected.to_uppercase();
+    }
+    let mut original = original.chars();
+    inflected
+        .chars()
+        .map(|c| match original.next() {
+            Some(o) if o.is_uppercase() => c.to_uppercase().next().unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+fn english() -> &'static Inflector {
+    static INFLECTOR: OnceLock<Inflector> = OnceLock::new();
+    INFLECTOR.get_or_init(Inflector::english)
+}
+
+/// Returns the plural form of the last word, keeping its case.
+///
+/// # Example
+/// ```rust
+/// use lo_::pluralize;
+///
+/// assert_eq!(pluralize("post"), "posts");
+/// assert_eq!(pluralize("Person"), "People");
+/// assert_eq!(pluralize("LineItem"), "LineItems");
+/// assert_eq!(pluralize("CATEGORY"), "CATEGORIES");
+/// assert_eq!(pluralize("sheep"), "sheep");
+///
+/// ```
+pub fn pluralize(s: &str) -> String {
+    english().pluralize(s)
+}
+
+/// Returns the singular form of the last word, keeping its case.
+///
+/// # Example
+/// ```rust
+/// use lo_::singularize;
+///
+/// assert_eq!(singularize("posts"), "post");
+/// assert_eq!(singularize("Children"), "Child");
+/// assert_eq!(singularize("user_addresses"), "user_address");
+///
+/// ```
+pub fn singularize(s: &str) -> String {
+    english().singularize(s)
+}
+
+/// Turns an identifier into a human-readable label.
+///
+/// # Example
+/// ```rust
+/// use lo_::humanize;
+///
+/// assert_eq!(humanize("employee_salary"), "Employee salary");
+/// assert_eq!(humanize("author_id"), "Author");
+/// assert_eq!(humanize("createdAt"), "Created at");
+///
+/// ```
+pub fn humanize(s: &str) -> String {
+    english().humanize(s)
+}
+
+/// Turns a type name into a snake_case plural table name.
+///
+/// # Example
+/// ```rust
+/// use lo_::tableize;
+///
+/// assert_eq!(tableize("RawScaledScorer"), "raw_scaled_scorers");
+/// assert_eq!(tableize("Person"), "people");
+///
+/// ```
+pub fn tableize(s: &str) -> String {
+    english().tableize(s)
+}
+
+/// Turns a table name into a singular PascalCase type name.
+///
+/// # Example
+/// ```rust
+/// use lo_::classify;
+///
+/// assert_eq!(classify("ham_and_eggs"), "HamAndEgg");
+/// assert_eq!(classify("public.people"), "Person");
+///
+/// ```
+pub fn classify(s: &str) -> String {
+    english().classify(s)
+}
+
+/// Turns a type name into a foreign key column name.
+///
+/// # Example
+/// ```rust
+/// use lo_::foreign_key;
+///
+/// assert_eq!(foreign_key("Message"), "message_id");
+/// assert_eq!(foreign_key("Admin::LineItem"), "line_item_id");
+///
+/// ```
+pub fn foreign_key(s: &str) -> String {
+    english().foreign_key(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: &[(&str, &str)] = &[
+        ("search", "searches"),
+        ("switch", "switches"),
+        ("fix", "fixes"),
+        ("box", "boxes"),
+        ("process", "processes"),
+        ("address", "addresses"),
+        ("case", "cases"),
+        ("stack", "stacks"),
+        ("wish", "wishes"),
+        ("category", "categories"),
+        ("query", "queries"),
+        ("ability", "abilities"),
+        ("agency", "agencies"),
+        ("movie", "movies"),
+        ("archive", "archives"),
+        ("index", "indices"),
+        ("wife", "wives"),
+        ("half", "halves"),
+        ("basis", "bases"),
+        ("diagnosis", "diagnoses"),
+        ("datum", "data"),
+        ("medium", "media"),
+        ("analysis", "analyses"),
+        ("node_child", "node_children"),
+        ("experience", "experiences"),
+        ("day", "days"),
+        ("comment", "comments"),
+        ("status", "statuses"),
+        ("bus", "buses"),
+        ("octopus", "octopi"),
+        ("alias", "aliases"),
+        ("matrix", "matrices"),
+        ("vertex", "vertices"),
+        ("axis", "axes"),
+        ("mouse", "mice"),
+        ("ox", "oxen"),
+        ("quiz", "quizzes"),
+        ("tomato", "tomatoes"),
+        ("shoe", "shoes"),
+        ("horse", "horses"),
+        ("edge", "edges"),
+    ];
+
+    #[test]
+    fn test_round_trips() {
+        for (singular, plural) in WORDS {
+            assert_eq!(pluralize(singular), *plural, "pluralize({singular})");
+            assert_eq!(singularize(plural), *singular, "singularize({plural})");
+            assert_eq!(pluralize(plural), *plural, "pluralize({plural})");
+        }
+    }
+
+    #[test]
+    fn test_keeps_case() {
+        assert_eq!(pluralize("Mouse"), "Mice");
+        assert_eq!(pluralize("USER"), "USERS");
+        assert_eq!(pluralize("API key"), "API keys");
+        assert_eq!(singularize("iPhones"), "iPhone");
+        assert_eq!(pluralize("userAccount"), "userAccounts");
+        assert_eq!(pluralize(""), "");
+    }
+
+    #[test]
+    fn test_uncountable_and_irregular_last_word() {
+        assert_eq!(pluralize("breaking news"), "breaking news");
+        assert_eq!(pluralize("admin_person"), "admin_people");
+        assert_eq!(singularize("people"), "person");
+        assert_eq!(pluralize("people"), "people");
+    }
+
+    #[test]
+    fn test_custom_rules_take_precedence() {
+        let inflector = Inflector::new().plural(Regex::new("$").unwrap(), "z");
+        assert_eq!(inflector.pluralize("cat"), "catz");
+        assert_eq!(inflector.singularize("catz"), "catz");
+
+        let inflector = Inflector::default().irregular("Person", "persons");
+        assert_eq!(inflector.pluralize("person"), "persons");
+    }
+
+    #[test]
+    fn test_naming_helpers() {
+        assert_eq!(tableize("LineItem"), "line_items");
+        assert_eq!(classify("line_items"), "LineItem");
+        assert_eq!(classify(&tableize("Person")), "Person");
+        assert_eq!(foreign_key("Person"), "person_id");
+        assert_eq!(humanize("id"), "Id");
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt::Write;
+
+/// Escapes a string for use inside a JSON string literal, without adding the
+/// surrounding quotes.
+///
+/// `"` and `\` are backslash-escaped, the usual control characters use their
+/// short forms (`\n`, `\t`, …) and the remaining ones `\u00XX`.
+///
+/// # Example
+/// ```rust
+/// use lo_::json_escape;
+///
+/// let message = "say \"hi\"\n\tC:\\temp";
+/// assert_eq!(json_escape(message), r#"say \"hi\"\n\tC:\\temp"#);
+/// assert_eq!(json_escape("\u{1}"), r"\u0001");
+///
+/// ```
+pub fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_control_characters() {
+        assert_eq!(json_escape("\u{0}\u{8}\u{c}\u{1f} "), r"\u0000\b\f\u001f ");
+        assert_eq!(json_escape("ünï 😀 /"), "ünï 😀 /");
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_parses_as_json() {
+        let text = "quote \" slash \\ nl \n bell \u{7} emoji 😀";
+        let parsed: String = serde_json::from_str(&format!("\"{}\"", json_escape(text))).unwrap();
+        assert_eq!(parsed, text);
+    }
+}
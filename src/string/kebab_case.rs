@@ -1,12 +1,12 @@
+use crate::string::deburr;
 use unicode_general_category::get_general_category;
 use unicode_general_category::GeneralCategory;
-use unicode_normalization::UnicodeNormalization;
 
 /// Converts a string to [kebab-case](https://developer.mozilla.org/en-US/docs/Glossary/Kebab_case).
 ///
 /// ### Behavior:
 /// - Converts all characters to lowercase.
-/// - Normalizes Unicode characters to ASCII (e.g., "résumé" → "resume").
+/// - Normalizes Unicode characters to ASCII with [`deburr`] (e.g., "résumé" → "resume", "ß" → "ss").
 /// - Replaces spaces and underscores with hyphens.
 /// - Removes punctuation and special characters (e.g., emojis, symbols).
 /// - Trims extra hyphens and avoids duplicates.
@@ -22,8 +22,8 @@ use unicode_normalization::UnicodeNormalization;
 /// ```
 ///
 pub fn kebab_case(s: &str) -> String {
-    let cleaned = s
-        .nfkd() // Normalize: café → c + a + f + e + ́
+    let cleaned = deburr(s) // Normalize: café → cafe, straße → strasse
+        .chars()
         .filter(|c| {
             // Remove combining marks and non-word symbols
             matches!(
@@ -63,6 +63,12 @@ mod tests {
         assert_eq!(kebab_case("Café Déjà Vu"), "cafe-deja-vu");
     }
 
+    #[test]
+    fn handles_ligatures_and_stroked_letters() {
+        assert_eq!(kebab_case("Große Straße"), "grosse-strasse");
+        assert_eq!(kebab_case("Søren Ærø"), "soren-aero");
+    }
+
     #[test]
     fn strips_symbols_and_emojis() {
         assert_eq!(kebab_case("Rust 💖 is 🔥"), "rust-is");
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 /// Converts the first character of string to lower case.
 ///
 /// # Example
@@ -9,24 +11,61 @@
 /// ```
 ///
 pub fn lower_first(string: &str) -> String {
-    let mut result = String::with_capacity(string.len());
-    let mut is_first_char = true;
+    lower_first_cow(string).into_owned()
+}
 
-    for c in string.chars() {
-        if is_first_char && c.is_alphanumeric() {
-            result.push(c.to_ascii_lowercase());
-            is_first_char = false;
-        } else {
-            result.push(c);
+/// Like [`lower_first`], but borrows the input when its first character is already lower case.
+///
+/// # Example
+/// ```rust
+/// use lo_::lower_first_cow;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(lower_first_cow("fred"), Cow::Borrowed(_)));
+/// assert_eq!(lower_first_cow("Fred"), "fred");
+///
+/// ```
+pub fn lower_first_cow(string: &str) -> Cow<'_, str> {
+    match first_alphanumeric(string) {
+        Some((_, c)) if c.is_ascii_uppercase() => {
+            let mut result = String::with_capacity(string.len());
+            lower_first_into(string, &mut result);
+            Cow::Owned(result)
+        }
+        _ => Cow::Borrowed(string),
+    }
+}
+
+/// Like [`lower_first`], but appends the result to `out` instead of allocating.
+///
+/// # Example
+/// ```rust
+/// use lo_::lower_first_into;
+///
+/// let mut out = String::from("> ");
+/// lower_first_into("Fred", &mut out);
+/// assert_eq!(out, "> fred");
+///
+/// ```
+pub fn lower_first_into(string: &str, out: &mut String) {
+    match first_alphanumeric(string) {
+        Some((i, c)) => {
+            out.push_str(&string[..i]);
+            out.push(c.to_ascii_lowercase());
+            out.push_str(&string[i + c.len_utf8()..]);
         }
+        None => out.push_str(string),
     }
+}
 
-    result
+/// Byte index and value of the first alphanumeric char.
+pub(crate) fn first_alphanumeric(string: &str) -> Option<(usize, char)> {
+    string.char_indices().find(|(_, c)| c.is_alphanumeric())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::lower_first;
+    use super::*;
 
     #[test]
     fn test_lower_first_empty_string() {
@@ -71,4 +110,25 @@ mod tests {
             "  this is a test".to_string()
         );
     }
+
+    #[test]
+    fn test_lower_first_cow_borrows_fast_path() {
+        let input = "  already lower";
+        match lower_first_cow(input) {
+            Cow::Borrowed(s) => assert!(std::ptr::eq(s, input)),
+            Cow::Owned(_) => panic!("expected a borrowed result"),
+        }
+        assert!(matches!(lower_first_cow("123"), Cow::Borrowed(_)));
+        assert!(matches!(lower_first_cow("Rust"), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_lower_first_into_appends() {
+        let mut out = String::with_capacity(64);
+        let capacity = out.capacity();
+        lower_first_into("Hello", &mut out);
+        lower_first_into(" World", &mut out);
+        assert_eq!(out, "hello world");
+        assert_eq!(out.capacity(), capacity);
+    }
 }
@@ -0,0 +1,192 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Masks all but the first `visible_prefix` and last `visible_suffix` grapheme
+/// clusters of a string with `mask_char`.
+///
+/// If the visible parts would cover the whole string, everything is masked so
+/// short secrets are never revealed in full.
+///
+/// # Example
+/// ```rust
+/// use lo_::mask;
+///
+/// assert_eq!(mask("4111111111111111", 0, 4, '*'), "************1111");
+/// assert_eq!(mask("Zoë Saldaña", 2, 2, '•'), "Zo•••••••ña");
+/// assert_eq!(mask("abc", 2, 2, '*'), "***");
+///
+/// ```
+pub fn mask(s: &str, visible_prefix: usize, visible_suffix: usize, mask_char: char) -> String {
+    let graphemes: Vec<&str> = s.graphemes(true).collect();
+    let len = graphemes.len();
+    let (prefix, suffix) = if visible_prefix + visible_suffix >= len {
+        (0, 0)
+    } else {
+        (visible_prefix, visible_suffix)
+    };
+
+    let mut out = String::with_capacity(s.len());
+    out.push_str(&graphemes[..prefix].concat());
+    out.extend(std::iter::repeat_n(mask_char, len - prefix - suffix));
+    out.push_str(&graphemes[len - suffix..].concat());
+    out
+}
+
+/// Masks the local part of an email address except its first character,
+/// keeping the domain. Returns `None` if `s` is not shaped like an email.
+///
+/// # Example
+/// ```rust
+/// use lo_::mask_email;
+///
+/// assert_eq!(mask_email("john.doe@example.com").as_deref(), Some("j*******@example.com"));
+/// assert_eq!(mask_email("not an email"), None);
+///
+/// ```
+pub fn mask_email(s: &str) -> Option<String> {
+    let (local, domain) = s.rsplit_once('@')?;
+    let valid = !local.is_empty()
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !s.chars().any(char::is_whitespace);
+    if !valid {
+        return None;
+    }
+    let local = match local.graphemes(true).count() {
+        1 => mask(local, 0, 0, '*'),
+        _ => mask(local, 1, 0, '*'),
+    };
+    Some(format!("{local}@{domain}"))
+}
+
+/// Masks every digit of a credit-card number except the last four, keeping
+/// spaces and dashes. Returns `None` unless the number has 12 to 19 digits and
+/// passes the Luhn check.
+///
+/// # Example
+/// ```rust
+/// use lo_::mask_credit_card;
+///
+/// assert_eq!(mask_credit_card("4111 1111 1111 1111").as_deref(), Some("**** **** **** 1111"));
+/// assert_eq!(mask_credit_card("4111 1111 1111 1112"), None);
+///
+/// ```
+pub fn mask_credit_card(s: &str) -> Option<String> {
+    if !s
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == ' ' || c == '-')
+        || !luhn_valid(s)
+    {
+        return None;
+    }
+    let digits = s.chars().filter(char::is_ascii_digit).count();
+    if !(12..=19).contains(&digits) {
+        return None;
+    }
+    Some(mask_digits(s, digits - 4))
+}
+
+/// Masks every digit of a phone number except the last four, keeping a leading
+/// `+` and the punctuation. Returns `None` unless the number has 7 to 15 digits
+/// and only contains digits, spaces, `+`, `-`, `.`, `(` and `)`.
+///
+/// # Example
+/// ```rust
+/// use lo_::mask_phone;
+///
+/// assert_eq!(mask_phone("+1 (555) 123-4567").as_deref(), Some("+* (***) ***-4567"));
+/// assert_eq!(mask_phone("call me"), None);
+///
+/// ```
+pub fn mask_phone(s: &str) -> Option<String> {
+    if !s
+        .chars()
+        .all(|c| c.is_ascii_digit() || " +-.()".contains(c))
+    {
+        return None;
+    }
+    let digits = s.chars().filter(char::is_ascii_digit).count();
+    if !(7..=15).contains(&digits) {
+        return None;
+    }
+    Some(mask_digits(s, digits - 4))
+}
+
+/// Replaces the first `count` ASCII digits of `s` with `*`.
+fn mask_digits(s: &str, count: usize) -> String {
+    let mut remaining = count;
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_digit() && remaining > 0 {
+                remaining -= 1;
+                '*'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Luhn checksum over the ASCII digits of `s`, ignoring everything else.
+pub(crate) fn luhn_valid(s: &str) -> bool {
+    let mut sum = 0;
+    let mut count = 0;
+    for digit in s
+        .bytes()
+        .rev()
+        .filter(u8::is_ascii_digit)
+        .map(|b| (b - b'0') as u32)
+    {
+        sum += match count % 2 {
+            0 => digit,
+            _ if digit > 4 => digit * 2 - 9,
+            _ => digit * 2,
+        };
+        count += 1;
+    }
+    count > 0 && sum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_graphemes() {
+        assert_eq!(mask("👨‍👩‍👧e\u{301}xyz", 2, 1, '*'), "👨‍👩‍👧e\u{301}**z");
+        assert_eq!(mask("", 1, 1, '*'), "");
+        assert_eq!(mask("secret", 0, 0, '#'), "######");
+    }
+
+    #[test]
+    fn test_mask_email() {
+        assert_eq!(mask_email("a@b.io").as_deref(), Some("*@b.io"));
+        assert_eq!(mask_email("@b.io"), None);
+        assert_eq!(mask_email("a@localhost"), None);
+        assert_eq!(mask_email("a b@c.io"), None);
+    }
+
+    #[test]
+    fn test_luhn() {
+        assert!(luhn_valid("79927398713"));
+        assert!(!luhn_valid("79927398710"));
+        assert!(luhn_valid("5555-5555-5555-4444"));
+        assert!(!luhn_valid(""));
+    }
+
+    #[test]
+    fn test_mask_credit_card() {
+        assert_eq!(
+            mask_credit_card("5555-5555-5555-4444").as_deref(),
+            Some("****-****-****-4444")
+        );
+        assert_eq!(mask_credit_card("79927398713"), None);
+        assert_eq!(mask_credit_card("4111x1111 1111 1111"), None);
+    }
+
+    #[test]
+    fn test_mask_phone() {
+        assert_eq!(mask_phone("555.123.4567").as_deref(), Some("***.***.4567"));
+        assert_eq!(mask_phone("12345"), None);
+    }
+}
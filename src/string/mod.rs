@@ -37,23 +37,141 @@ pub use words::*;
 mod template;
 pub use template::*;
 
+mod regex_cache;
+pub use regex_cache::*;
+
+mod escape_html;
+pub use escape_html::*;
+
+mod escape_regex;
+pub use escape_regex::*;
+
+mod shell_quote;
+pub use shell_quote::*;
+
+mod json_escape;
+pub use json_escape::*;
+
+mod percent_encode;
+pub use percent_encode::*;
+
+mod random_string;
+#[cfg(feature = "random")]
+pub use random_string::*;
+
+mod mask;
+pub use mask::*;
+
+mod redactor;
+pub use redactor::*;
+
+mod deburr;
+pub use deburr::*;
+
+mod transliterate;
+pub use transliterate::*;
+
 mod slugify;
 pub use slugify::*;
 
+mod slug_registry;
+pub use slug_registry::*;
+
+mod similarity;
+pub use similarity::*;
+
+mod fuzzy_matcher;
+pub use fuzzy_matcher::*;
+
+mod diff;
+pub use diff::*;
+
+mod truncate;
+pub use truncate::*;
+
 mod truncate_middle;
 pub use truncate_middle::*;
 
+mod display_width;
+pub use display_width::*;
+
 mod safe_parse;
 pub use safe_parse::*;
 
+mod parse_lenient;
+pub use parse_lenient::*;
+
+mod format_number;
+pub use format_number::*;
+
+mod compact_number;
+pub use compact_number::*;
+
+mod ordinal;
+pub use ordinal::*;
+
+mod format_bytes;
+pub use format_bytes::*;
+
+mod format_duration;
+pub use format_duration::*;
+
 mod str_split;
 pub use str_split::*;
 
+mod split_with;
+pub use split_with::*;
+
+mod shell_split;
+pub use shell_split::*;
+
+mod csv_split;
+pub use csv_split::*;
+
 mod str_rev;
 pub use str_rev::*;
 
 mod str_pad;
 pub use str_pad::*;
 
+mod dedent;
+pub use dedent::*;
+
+mod indent;
+pub use indent::*;
+
+mod strip_margin;
+pub use strip_margin::*;
+
+mod trim_lines;
+pub use trim_lines::*;
+
+mod normalize_line_endings;
+pub use normalize_line_endings::*;
+
 mod wordwrap;
 pub use wordwrap::*;
+
+mod hyphenator;
+pub use hyphenator::*;
+
+mod wrap_algorithm;
+pub use wrap_algorithm::*;
+
+mod wrap_lines;
+pub use wrap_lines::*;
+
+mod wrap_markdown;
+pub use wrap_markdown::*;
+
+mod stream_transform;
+pub use stream_transform::*;
+
+mod text_table;
+pub use text_table::*;
+
+mod case;
+pub use case::*;
+
+mod inflector;
+pub use inflector::*;
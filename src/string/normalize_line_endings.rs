@@ -0,0 +1,76 @@
+/// The line ending written by [`normalize_line_endings`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl LineEnding {
+    /// The line ending as a string.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Rewrites every `\n` and `\r\n` line ending as `ending`.
+///
+/// Lines are split like [`str::lines`]; a lone `\r` is not a line ending and is kept.
+///
+/// # Example
+/// ```rust
+/// use lo_::{normalize_line_endings, LineEnding};
+///
+/// assert_eq!(normalize_line_endings("a\r\nb\nc", LineEnding::Lf), "a\nb\nc");
+/// assert_eq!(normalize_line_endings("a\r\nb\n", LineEnding::CrLf), "a\r\nb\r\n");
+///
+/// ```
+pub fn normalize_line_endings(s: &str, ending: LineEnding) -> String {
+    let mut out = String::with_capacity(s.len());
+    for (line, end) in split_line_endings(s) {
+        out.push_str(line);
+        if !end.is_empty() {
+            out.push_str(ending.as_str());
+        }
+    }
+    out
+}
+
+/// Splits `s` into `(line, ending)` pairs, where `ending` is `"\n"`, `"\r\n"`
+/// or empty for a last line without one. The lines are those of [`str::lines`].
+pub(crate) fn split_line_endings(s: &str) -> impl Iterator<Item = (&str, &str)> {
+    s.split_inclusive('\n').map(|line| {
+        let body = match line.strip_suffix('\n') {
+            Some(body) => body.strip_suffix('\r').unwrap_or(body),
+            None => line,
+        };
+        (body, &line[body.len()..])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_matches_lines() {
+        for s in ["", "a", "a\n", "a\r\nb\r\n\r\nc", "\n\n", "a\rb\n"] {
+            let lines: Vec<&str> = split_line_endings(s).map(|(line, _)| line).collect();
+            assert_eq!(lines, s.lines().collect::<Vec<_>>());
+            let joined: String = split_line_endings(s)
+                .map(|(l, e)| format!("{l}{e}"))
+                .collect();
+            assert_eq!(joined, s);
+        }
+    }
+
+    #[test]
+    fn test_keeps_lone_cr() {
+        assert_eq!(normalize_line_endings("a\rb\r\n", LineEnding::Lf), "a\rb\n");
+    }
+}
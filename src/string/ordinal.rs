@@ -0,0 +1,62 @@
+/// Formats an integer as an English ordinal.
+///
+/// The result parses back with [`parse_lenient`](crate::parse_lenient).
+///
+/// # Example
+/// ```rust
+/// use lo_::ordinal;
+///
+/// assert_eq!(ordinal(1), "1st");
+/// assert_eq!(ordinal(22), "22nd");
+/// assert_eq!(ordinal(113), "113th");
+///
+/// ```
+pub fn ordinal(n: i64) -> String {
+    format!("{n}{}", ordinal_suffix(n.unsigned_abs().into()))
+}
+
+/// The ordinal suffix (`st`, `nd`, `rd` or `th`) for `n`.
+pub(crate) fn ordinal_suffix(n: u128) -> &'static str {
+    match (n % 100, n % 10) {
+        (11..=13, _) => "th",
+        (_, 1) => "st",
+        (_, 2) => "nd",
+        (_, 3) => "rd",
+        _ => "th",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::parse_lenient;
+
+    #[test]
+    fn test_ordinal() {
+        let expected = [
+            (0, "0th"),
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (21, "21st"),
+            (102, "102nd"),
+            (111, "111th"),
+            (-1, "-1st"),
+        ];
+        for (n, s) in expected {
+            assert_eq!(ordinal(n), s);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for n in [1, 2, 3, 11, 22, 113, 1001] {
+            assert_eq!(parse_lenient::<i64>(&ordinal(n)), Ok(n));
+        }
+        assert!(parse_lenient::<i64>("2st").is_err());
+    }
+}
@@ -0,0 +1,462 @@
+use crate::string::compact_number::compact_exponent;
+use crate::string::ordinal::ordinal_suffix;
+use std::error::Error;
+use std::fmt;
+use std::num::IntErrorKind;
+use std::time::Duration;
+
+/// Error returned by [`parse_lenient`], explaining why the input was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The input that failed to parse.
+    pub input: String,
+    /// The type it was parsed as, e.g. `u8` or `Duration`.
+    pub target: &'static str,
+    /// Why the input was rejected.
+    pub reason: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(input: &str, target: &'static str, reason: impl Into<String>) -> Self {
+        Self {
+            input: input.to_string(),
+            target,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot parse {:?} as {}: {}",
+            self.input, self.target, self.reason
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A number of bytes, parsed from sizes like `10MiB`, `2GB` or `512`.
+///
+/// Decimal units (`kB`, `MB`, …) are powers of 1000 and binary units
+/// (`KiB`, `MiB`, …) are powers of 1024. Units are case-insensitive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(pub u64);
+
+/// Types that [`parse_lenient`] can parse.
+pub trait LenientParse: Sized {
+    /// Parses `s`, explaining the failure in a [`ParseError`].
+    fn parse_lenient(s: &str) -> Result<Self, ParseError>;
+}
+
+/// Parses a string into `T`, accepting the human-friendly forms found in env
+/// vars and CLI flags, and returning a readable error when it fails.
+///
+/// - `bool`: `true`/`false`, `yes`/`no`, `y`/`n`, `on`/`off`, `1`/`0`
+/// - integers: `_` and `,` separators (`1_000`, `1,000`), `0x`, `0o`, `0b` literals,
+///   and ordinals (`22nd`)
+/// - floats: separators, percentages (`50%` is `0.5`) and `K`/`M`/`B`/`T` suffixes (`1.2K`)
+/// - [`Duration`]: `1h30m`, `1.5s`, `250ms`, `2 days`, or a bare number of seconds
+/// - [`ByteSize`]: `10MiB`, `2GB`, `1.5 kB`, or a bare number of bytes
+///
+/// Surrounding whitespace is ignored.
+///
+/// # Example
+/// ```rust
+/// use lo_::{parse_lenient, ByteSize};
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_lenient::<bool>("yes"), Ok(true));
+/// assert_eq!(parse_lenient::<u32>("1,000,000"), Ok(1_000_000));
+/// assert_eq!(parse_lenient::<u8>("0xFF"), Ok(255));
+/// assert_eq!(parse_lenient::<f64>("50%"), Ok(0.5));
+/// assert_eq!(parse_lenient::<Duration>("1h30m"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parse_lenient::<ByteSize>("10MiB"), Ok(ByteSize(10 * 1024 * 1024)));
+///
+/// let err = parse_lenient::<u8>("300").unwrap_err();
+/// assert_eq!(err.to_string(), r#"cannot parse "300" as u8: number too large for u8"#);
+///
+/// ```
+pub fn parse_lenient<T: LenientParse>(s: &str) -> Result<T, ParseError> {
+    T::parse_lenient(s)
+}
+
+impl LenientParse for bool {
+    fn parse_lenient(s: &str) -> Result<Self, ParseError> {
+        match s.trim().to_lowercase().as_str() {
+            "true" | "yes" | "y" | "on" | "1" => Ok(true),
+            "false" | "no" | "n" | "off" | "0" => Ok(false),
+            _ => Err(ParseError::new(
+                s,
+                "bool",
+                "expected true/false, yes/no, on/off or 1/0",
+            )),
+        }
+    }
+}
+
+macro_rules! impl_lenient_int {
+    ($($t:ty),*) => {$(
+        impl LenientParse for $t {
+            fn parse_lenient(s: &str) -> Result<Self, ParseError> {
+                let target = stringify!($t);
+                let (negative, digits, radix) = split_radix(strip_ordinal(s.trim()));
+                let digits = strip_separators(digits, radix)
+                    .map_err(|reason| ParseError::new(s, target, reason))?;
+                let signed = if negative { format!("-{digits}") } else { digits };
+                <$t>::from_str_radix(&signed, radix).map_err(|e| {
+                    let reason = match e.kind() {
+                        IntErrorKind::Empty => "no digits".to_string(),
+                        IntErrorKind::PosOverflow => format!("number too large for {target}"),
+                        IntErrorKind::NegOverflow => format!("number too small for {target}"),
+                        _ => "invalid digit".to_string(),
+                    };
+                    ParseError::new(s, target, reason)
+                })
+            }
+        }
+    )*};
+}
+
+impl_lenient_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_lenient_float {
+    ($($t:ty),*) => {$(
+        impl LenientParse for $t {
+            fn parse_lenient(s: &str) -> Result<Self, ParseError> {
+                let target = stringify!($t);
+                let trimmed = s.trim();
+                let (number, percent) = match trimmed.strip_suffix('%') {
+                    Some(number) => (number.trim_end(), true),
+                    None => (trimmed, false),
+                };
+                let (number, exponent) = split_compact(number);
+                let mut number = strip_separators(number, 10)
+                    .map_err(|reason| ParseError::new(s, target, reason))?;
+                if exponent > 0 {
+                    number = format!("{number}e{exponent}");
+                }
+                let value: $t = number
+                    .parse()
+                    .map_err(|_| ParseError::new(s, target, "invalid number"))?;
+                Ok(if percent { value / 100.0 } else { value })
+            }
+        }
+    )*};
+}
+
+impl_lenient_float!(f32, f64);
+
+impl LenientParse for Duration {
+    fn parse_lenient(s: &str) -> Result<Self, ParseError> {
+        let error = |reason: &str| ParseError::new(s, "Duration", reason);
+        let trimmed = s.trim();
+        if trimmed.starts_with('-') {
+            return Err(error("durations cannot be negative"));
+        }
+
+        let mut rest = trimmed;
+        let mut nanos: u128 = 0;
+        let mut parts = 0;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+            if rest.is_empty() {
+                break;
+            }
+
+            let (number, tail) = split_number(rest);
+            if number.is_empty() {
+                return Err(error("expected a number"));
+            }
+            let tail = tail.trim_start();
+            let unit_len = tail
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(tail.len());
+            let (unit, tail) = tail.split_at(unit_len);
+
+            let scale = match unit.to_lowercase().as_str() {
+                "" if parts == 0 && tail.trim().is_empty() => 1_000_000_000,
+                "" => return Err(error(&format!("missing unit after {number:?}"))),
+                "ns" | "nanosecond" | "nanoseconds" => 1,
+                "us" | "µs" | "microsecond" | "microseconds" => 1_000,
+                "ms" | "millisecond" | "milliseconds" => 1_000_000,
+                "s" | "sec" | "secs" | "second" | "seconds" => 1_000_000_000,
+                "m" | "min" | "mins" | "minute" | "minutes" => 60_000_000_000,
+                "h" | "hr" | "hrs" | "hour" | "hours" => 3_600_000_000_000,
+                "d" | "day" | "days" => 86_400_000_000_000,
+                "w" | "week" | "weeks" => 604_800_000_000_000,
+                _ => return Err(error(&format!("unknown unit {unit:?}"))),
+            };
+
+            let value = scaled(number, scale).map_err(|reason| error(&reason))?;
+            nanos = nanos
+                .checked_add(value)
+                .ok_or_else(|| error("duration too large"))?;
+            parts += 1;
+            rest = tail;
+        }
+
+        if parts == 0 {
+            return Err(error("empty duration"));
+        }
+        let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| error("duration too large"))?;
+        Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+    }
+}
+
+impl LenientParse for ByteSize {
+    fn parse_lenient(s: &str) -> Result<Self, ParseError> {
+        let error = |reason: &str| ParseError::new(s, "ByteSize", reason);
+        let trimmed = s.trim();
+        let (number, unit) = split_number(trimmed);
+        if number.is_empty() {
+            return Err(error("expected a number"));
+        }
+
+        let unit = unit.trim().to_lowercase();
+        let (prefix, binary) = match unit.strip_suffix("bytes").or(unit.strip_suffix("byte")) {
+            Some(prefix) => (prefix, false),
+            None => match unit.strip_suffix("ib").or(unit.strip_suffix('i')) {
+                Some(prefix) => (prefix, true),
+                None => (unit.strip_suffix('b').unwrap_or(&unit), false),
+            },
+        };
+        let power = match prefix {
+            "" if !binary => 0,
+            "k" => 1,
+            "m" => 2,
+            "g" => 3,
+            "t" => 4,
+            "p" => 5,
+            "e" => 6,
+            _ => return Err(error(&format!("unknown unit {unit:?}"))),
+        };
+        let base: u128 = if binary { 1024 } else { 1000 };
+
+        let bytes = scaled(number, base.pow(power)).map_err(|reason| error(&reason))?;
+        u64::try_from(bytes)
+            .map(ByteSize)
+            .map_err(|_| error("size too large"))
+    }
+}
+
+/// Strips a matching ordinal suffix, e.g. `22nd` → `22`.
+fn strip_ordinal(s: &str) -> &str {
+    let Some(split) = s.len().checked_sub(2).filter(|&i| s.is_char_boundary(i)) else {
+        return s;
+    };
+    let (number, suffix) = s.split_at(split);
+    let unsigned = number.trim_start_matches(['-', '+']);
+    if !unsigned
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '_' || c == ',')
+    {
+        return s;
+    }
+    let digits: String = unsigned.chars().filter(char::is_ascii_digit).collect();
+    let last_two = digits[digits.len().saturating_sub(2)..].parse::<u128>();
+    match last_two {
+        Ok(n) if ordinal_suffix(n).eq_ignore_ascii_case(suffix) => number,
+        _ => s,
+    }
+}
+
+/// Splits a trailing [`compact_number`](crate::compact_number) suffix off a number,
+/// returning the power of ten it stands for.
+fn split_compact(s: &str) -> (&str, usize) {
+    let Some(last) = s.chars().last() else {
+        return (s, 0);
+    };
+    let number = &s[..s.len() - last.len_utf8()];
+    let ends_in_digit = number.ends_with(|c: char| c.is_ascii_digit());
+    match compact_exponent(last.encode_utf8(&mut [0; 4])) {
+        Some(exponent) if ends_in_digit => (number, exponent),
+        _ => (s, 0),
+    }
+}
+
+/// Splits an optional sign and `0x`/`0o`/`0b` prefix off an integer literal.
+fn split_radix(s: &str) -> (bool, &str, u32) {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let prefix = unsigned.get(..2).map(str::to_lowercase);
+    let radix = match prefix.as_deref() {
+        Some("0x") => 16,
+        Some("0o") => 8,
+        Some("0b") => 2,
+        _ => return (negative, unsigned, 10),
+    };
+    (negative, &unsigned[2..], radix)
+}
+
+/// Removes `_` and `,` digit separators. Each one must sit between two digits.
+fn strip_separators(s: &str, radix: u32) -> Result<String, String> {
+    let chars: Vec<char> = s.chars().collect();
+    let is_digit = |k: usize| chars.get(k).is_some_and(|c| c.is_digit(radix));
+    let mut out = String::with_capacity(s.len());
+
+    for (k, c) in chars.iter().enumerate() {
+        if *c == '_' || *c == ',' {
+            if k == 0 || !is_digit(k - 1) || !is_digit(k + 1) {
+                return Err(format!("misplaced separator {c:?}"));
+            }
+            continue;
+        }
+        out.push(*c);
+    }
+    Ok(out)
+}
+
+/// Splits a leading unsigned decimal number (with separators) off `s`.
+fn split_number(s: &str) -> (&str, &str) {
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+    let end = chars
+        .iter()
+        .enumerate()
+        .find(|(k, (_, c))| {
+            let separator = (*c == ',' || *c == '_')
+                && chars.get(k + 1).is_some_and(|(_, n)| n.is_ascii_digit());
+            !(c.is_ascii_digit() || *c == '.' || separator)
+        })
+        .map_or(s.len(), |(_, (i, _))| *i);
+    s.split_at(end)
+}
+
+/// Multiplies a decimal number like `1.5` by `scale`, rounding to the nearest integer.
+fn scaled(number: &str, scale: u128) -> Result<u128, String> {
+    let digits = strip_separators(number, 10)?;
+    let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+    if whole.is_empty() && fraction.is_empty() || fraction.contains('.') {
+        return Err(format!("invalid number {number:?}"));
+    }
+
+    let overflow = || "number too large".to_string();
+    let fraction = fraction.trim_end_matches('0');
+    let exponent = u32::try_from(fraction.len()).map_err(|_| overflow())?;
+    let divisor = 10u128.checked_pow(exponent).ok_or_else(overflow)?;
+    let mantissa: u128 = format!("{whole}{fraction}")
+        .parse()
+        .map_err(|_| overflow())?;
+
+    let product = mantissa.checked_mul(scale).ok_or_else(overflow)?;
+    Ok((product + divisor / 2) / divisor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bool_words() {
+        for word in ["yes", "ON", " true ", "1", "y"] {
+            assert_eq!(parse_lenient::<bool>(word), Ok(true));
+        }
+        for word in ["no", "Off", "false", "0", "n"] {
+            assert_eq!(parse_lenient::<bool>(word), Ok(false));
+        }
+        let err = parse_lenient::<bool>("maybe").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"cannot parse "maybe" as bool: expected true/false, yes/no, on/off or 1/0"#
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(parse_lenient::<i64>("1_000_000"), Ok(1_000_000));
+        assert_eq!(parse_lenient::<i64>("-1,234"), Ok(-1234));
+        assert_eq!(parse_lenient::<u32>("+42"), Ok(42));
+        assert_eq!(parse_lenient::<u16>("0xff_ff"), Ok(0xffff));
+        assert_eq!(parse_lenient::<u8>("0o17"), Ok(15));
+        assert_eq!(parse_lenient::<i8>("-0b101"), Ok(-5));
+    }
+
+    #[test]
+    fn test_integer_errors() {
+        let reason = |s| parse_lenient::<i8>(s).unwrap_err().reason;
+        assert_eq!(reason("128"), "number too large for i8");
+        assert_eq!(reason("-129"), "number too small for i8");
+        assert_eq!(reason("12a"), "invalid digit");
+        assert_eq!(reason(""), "no digits");
+        assert_eq!(reason("1__0"), "misplaced separator '_'");
+        assert_eq!(reason(",10"), "misplaced separator ','");
+        assert_eq!(
+            parse_lenient::<u8>("-1").unwrap_err().reason,
+            "invalid digit"
+        );
+    }
+
+    #[test]
+    fn test_ordinals_and_compact_numbers() {
+        assert_eq!(parse_lenient::<u32>("22nd"), Ok(22));
+        assert_eq!(parse_lenient::<i32>("-111TH"), Ok(-111));
+        assert_eq!(
+            parse_lenient::<u32>("22th").unwrap_err().reason,
+            "invalid digit"
+        );
+        assert_eq!(parse_lenient::<f64>("1.2K"), Ok(1200.0));
+        assert_eq!(parse_lenient::<f64>("-3.4m"), Ok(-3_400_000.0));
+        assert_eq!(parse_lenient::<f64>("2B"), Ok(2e9));
+        assert!(parse_lenient::<f64>("K").is_err());
+    }
+
+    #[test]
+    fn test_floats_and_percentages() {
+        assert_eq!(parse_lenient::<f64>("1,234.5"), Ok(1234.5));
+        assert_eq!(parse_lenient::<f64>("12.5%"), Ok(0.125));
+        assert_eq!(parse_lenient::<f32>("50 %"), Ok(0.5));
+        assert_eq!(
+            parse_lenient::<f64>("abc").unwrap_err().reason,
+            "invalid number"
+        );
+    }
+
+    #[test]
+    fn test_durations() {
+        let parse = |s| parse_lenient::<Duration>(s);
+        assert_eq!(parse("1h30m"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse("1h 2m 3s"), Ok(Duration::from_secs(3723)));
+        assert_eq!(parse("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse("2 days, 1 hour"), Ok(Duration::from_secs(176_400)));
+        assert_eq!(parse("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse("1w"), Ok(Duration::from_secs(604_800)));
+        assert_eq!(parse("3µs"), Ok(Duration::from_micros(3)));
+    }
+
+    #[test]
+    fn test_duration_errors() {
+        let reason = |s| parse_lenient::<Duration>(s).unwrap_err().reason;
+        assert_eq!(reason("5 fortnights"), r#"unknown unit "fortnights""#);
+        assert_eq!(reason("1h 30"), r#"missing unit after "30""#);
+        assert_eq!(reason("-5s"), "durations cannot be negative");
+        assert_eq!(reason(""), "empty duration");
+        assert_eq!(reason("h"), "expected a number");
+    }
+
+    #[test]
+    fn test_byte_sizes() {
+        let parse = |s| parse_lenient::<ByteSize>(s).map(|b| b.0);
+        assert_eq!(parse("10MiB"), Ok(10 * 1024 * 1024));
+        assert_eq!(parse("2GB"), Ok(2_000_000_000));
+        assert_eq!(parse("1.5 kb"), Ok(1500));
+        assert_eq!(parse("1.5KiB"), Ok(1536));
+        assert_eq!(parse("4K"), Ok(4000));
+        assert_eq!(parse("4Ki"), Ok(4096));
+        assert_eq!(parse("512"), Ok(512));
+        assert_eq!(parse("512 bytes"), Ok(512));
+        assert_eq!(parse("1,024B"), Ok(1024));
+    }
+
+    #[test]
+    fn test_byte_size_errors() {
+        let reason = |s| parse_lenient::<ByteSize>(s).unwrap_err().reason;
+        assert_eq!(reason("10 parsecs"), r#"unknown unit "parsecs""#);
+        assert_eq!(reason("MB"), "expected a number");
+        assert_eq!(reason("100EB"), "size too large");
+    }
+}
@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+
+/// Error returned by [`percent_decode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The `%` at byte `position` is not followed by two hex digits.
+    InvalidEscape { position: usize },
+    /// The decoded bytes are not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidEscape { position } => {
+                write!(f, "invalid percent escape at byte {position}")
+            }
+            DecodeError::InvalidUtf8 => write!(f, "decoded bytes are not valid UTF-8"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Percent-encodes a URL component such as a path segment or query value.
+///
+/// Everything except the RFC 3986 unreserved characters (`A-Z a-z 0-9 - . _ ~`)
+/// is encoded as `%XX` over its UTF-8 bytes, so `/`, `?`, `&`, `=` and spaces
+/// are all encoded.
+///
+/// # Example
+/// ```rust
+/// use lo_::percent_encode;
+///
+/// assert_eq!(percent_encode("a b&c=d/é"), "a%20b%26c%3Dd%2F%C3%A9");
+/// assert_eq!(percent_encode("safe-._~"), "safe-._~");
+///
+/// ```
+pub fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            out.push(byte as char);
+        } else {
+            let _ = write!(out, "%{byte:02X}");
+        }
+    }
+    out
+}
+
+/// Decodes `%XX` escapes in a URL component.
+///
+/// `+` is left as is; replace it first when decoding form data.
+///
+/// # Example
+/// ```rust
+/// use lo_::{percent_decode, DecodeError};
+///
+/// assert_eq!(percent_decode("a%20b%2Fc%C3%A9").unwrap(), "a b/cé");
+/// assert_eq!(percent_decode("100%"), Err(DecodeError::InvalidEscape { position: 3 }));
+///
+/// ```
+pub fn percent_decode(s: &str) -> Result<String, DecodeError> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let byte = bytes
+                .get(i + 1..i + 3)
+                .filter(|h| h.iter().all(u8::is_ascii_hexdigit))
+                .and_then(|h| u8::from_str_radix(std::str::from_utf8(h).ok()?, 16).ok())
+                .ok_or(DecodeError::InvalidEscape { position: i })?;
+            out.push(byte);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).map_err(|_| DecodeError::InvalidUtf8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let text = "q=rust & lo_ / 100% 😀 ~tilde";
+        assert_eq!(percent_decode(&percent_encode(text)).unwrap(), text);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            percent_decode("%zz"),
+            Err(DecodeError::InvalidEscape { position: 0 })
+        );
+        assert_eq!(
+            percent_decode("ab%4"),
+            Err(DecodeError::InvalidEscape { position: 2 })
+        );
+        assert_eq!(
+            percent_decode("%+1"),
+            Err(DecodeError::InvalidEscape { position: 0 })
+        );
+        assert_eq!(percent_decode("%FF"), Err(DecodeError::InvalidUtf8));
+        assert_eq!(
+            percent_decode("%e9").unwrap_err().to_string(),
+            "decoded bytes are not valid UTF-8"
+        );
+        assert_eq!(percent_decode("a+b").unwrap(), "a+b");
+    }
+}
@@ -0,0 +1,174 @@
+#[cfg(feature = "random")]
+use rand::Rng;
+
+#[cfg(feature = "random")]
+const ALPHANUMERIC: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+#[cfg(feature = "random")]
+const HEX: &str = "0123456789abcdef";
+#[cfg(feature = "random")]
+const URL_SAFE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_-";
+
+#[cfg(feature = "random")]
+const ADJECTIVES: &[&str] = &[
+    "amber", "brave", "calm", "clever", "cosmic", "crisp", "dusty", "eager", "fancy", "gentle",
+    "golden", "happy", "hidden", "jolly", "lively", "lucky", "mellow", "misty", "nimble", "noble",
+    "proud", "quiet", "rapid", "rustic", "silent", "silver", "sunny", "swift", "tidy", "vivid",
+    "wild", "witty",
+];
+#[cfg(feature = "random")]
+const NOUNS: &[&str] = &[
+    "badger", "breeze", "canyon", "cedar", "comet", "falcon", "fern", "forest", "glacier",
+    "harbor", "island", "lagoon", "lantern", "maple", "meadow", "meteor", "otter", "panda",
+    "pebble", "planet", "prairie", "raven", "river", "rocket", "sparrow", "summit", "thunder",
+    "tiger", "valley", "willow", "wolf", "zephyr",
+];
+
+/// The characters [`random_string`] draws from.
+#[cfg(feature = "random")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Charset<'a> {
+    /// `A-Z`, `a-z` and `0-9`.
+    Alphanumeric,
+    /// Lowercase hexadecimal digits.
+    Hex,
+    /// `A-Z`, `a-z`, `0-9`, `_` and `-`: safe in URLs and file names.
+    UrlSafe,
+    /// The chars of the given string.
+    Custom(&'a str),
+}
+
+/// Generates a random string of `len` chars drawn uniformly from `charset`,
+/// using the caller's RNG so results can be made reproducible.
+///
+/// Returns an empty string for an empty custom charset.
+///
+/// # Example
+/// ```rust
+/// use lo_::{random_string, Charset};
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let token = random_string(16, Charset::Hex, &mut rng);
+/// assert_eq!(token.len(), 16);
+/// assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+///
+/// // The same seed gives the same string.
+/// assert_eq!(token, random_string(16, Charset::Hex, &mut SmallRng::seed_from_u64(7)));
+///
+/// let pin = random_string(4, Charset::Custom("0123456789"), &mut rng);
+/// assert!(pin.chars().all(|c| c.is_ascii_digit()));
+///
+/// ```
+///
+/// # Features
+/// This function is only available when the `random` feature is enabled.
+#[cfg(feature = "random")]
+pub fn random_string<R: Rng + ?Sized>(len: usize, charset: Charset, rng: &mut R) -> String {
+    let chars: Vec<char> = match charset {
+        Charset::Alphanumeric => ALPHANUMERIC,
+        Charset::Hex => HEX,
+        Charset::UrlSafe => URL_SAFE,
+        Charset::Custom(chars) => chars,
+    }
+    .chars()
+    .collect();
+    if chars.is_empty() {
+        return String::new();
+    }
+    (0..len)
+        .map(|_| chars[rng.random_range(0..chars.len())])
+        .collect()
+}
+
+/// Generates a readable `adjective-noun-1234` slug, e.g. for preview
+/// environments or default resource names.
+///
+/// # Example
+/// ```rust
+/// use lo_::random_word_slug;
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let slug = random_word_slug(&mut SmallRng::seed_from_u64(1));
+/// let parts: Vec<&str> = slug.split('-').collect();
+/// assert_eq!(parts.len(), 3);
+/// assert_eq!(parts[2].len(), 4);
+///
+/// ```
+///
+/// # Features
+/// This function is only available when the `random` feature is enabled.
+#[cfg(feature = "random")]
+pub fn random_word_slug<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let adjective = ADJECTIVES[rng.random_range(0..ADJECTIVES.len())];
+    let noun = NOUNS[rng.random_range(0..NOUNS.len())];
+    let number: u16 = rng.random_range(1000..=9999);
+    format!("{adjective}-{noun}-{number}")
+}
+
+/// Generates a 21-char URL-safe ID in the style of nanoid, with about as many
+/// random bits as a UUID v4.
+///
+/// # Example
+/// ```rust
+/// use lo_::nanoid;
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let id = nanoid(&mut SmallRng::seed_from_u64(3));
+/// assert_eq!(id.len(), 21);
+/// assert!(id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'));
+///
+/// ```
+///
+/// # Features
+/// This function is only available when the `random` feature is enabled.
+#[cfg(feature = "random")]
+pub fn nanoid<R: Rng + ?Sized>(rng: &mut R) -> String {
+    random_string(21, Charset::UrlSafe, rng)
+}
+
+#[cfg(all(test, feature = "random"))]
+mod tests {
+    use super::*;
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    #[test]
+    fn test_deterministic_with_seed() {
+        let a = random_string(32, Charset::Alphanumeric, &mut SmallRng::seed_from_u64(42));
+        let b = random_string(32, Charset::Alphanumeric, &mut SmallRng::seed_from_u64(42));
+        let c = random_string(32, Charset::Alphanumeric, &mut SmallRng::seed_from_u64(43));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_charsets() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let custom = random_string(200, Charset::Custom("aé😀"), &mut rng);
+        assert_eq!(custom.chars().count(), 200);
+        assert!(custom.chars().all(|c| "aé😀".contains(c)));
+        // Every char of a small set shows up.
+        assert!("aé😀".chars().all(|c| custom.contains(c)));
+
+        assert_eq!(random_string(5, Charset::Custom(""), &mut rng), "");
+        assert_eq!(random_string(0, Charset::Hex, &mut rng), "");
+    }
+
+    #[test]
+    fn test_word_slug_shape() {
+        let mut rng = SmallRng::seed_from_u64(9);
+        for _ in 0..50 {
+            let slug = random_word_slug(&mut rng);
+            let parts: Vec<&str> = slug.split('-').collect();
+            assert!(ADJECTIVES.contains(&parts[0]));
+            assert!(NOUNS.contains(&parts[1]));
+            assert!((1000..=9999).contains(&parts[2].parse::<u16>().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_accepts_dyn_rng() {
+        let mut rng = SmallRng::seed_from_u64(5);
+        let rng: &mut dyn rand::RngCore = &mut rng;
+        assert_eq!(nanoid(rng).len(), 21);
+    }
+}
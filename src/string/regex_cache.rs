@@ -0,0 +1,159 @@
+use regex::Regex;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+const DEFAULT_CAPACITY: usize = 256;
+
+/// A thread-safe cache of compiled regular expressions, keyed by pattern.
+///
+/// Compiling a [`Regex`] is far more expensive than matching with one, so code
+/// that builds the same patterns over and over (per record, per request)
+/// should compile each once. [`RegexCache::get`] returns a cheap clone of the
+/// cached regex, which can be passed anywhere a `Regex` is taken, such as
+/// [`words_with_pattern`](crate::words_with_pattern),
+/// [`TruncateOptions::separator`](crate::TruncateOptions::separator) or
+/// [`Redactor::rule`](crate::Redactor::rule).
+///
+/// When the cache is full, the oldest pattern is evicted.
+///
+/// # Example
+/// ```rust
+/// use lo_::{words_with_pattern, RegexCache};
+///
+/// let cache = RegexCache::new();
+/// for line in ["fred, barney", "wilma, & betty"] {
+///     let words = words_with_pattern(line, Some(cache.get("[^, ]+").unwrap()));
+///     assert_eq!(words.len(), 2 + line.contains('&') as usize);
+/// }
+/// assert_eq!(cache.len(), 1);
+/// assert!(cache.get("(unclosed").is_err());
+///
+/// ```
+#[derive(Debug)]
+pub struct RegexCache {
+    capacity: usize,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    regexes: HashMap<String, Regex>,
+    order: VecDeque<String>,
+}
+
+impl Default for RegexCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexCache {
+    /// Creates a cache holding up to 256 patterns.
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// Creates a cache holding up to `capacity` patterns. A capacity of `0`
+    /// disables caching.
+    pub fn with_capacity(capacity: usize) -> Self {
+        RegexCache {
+            capacity,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Returns the compiled regex for `pattern`, compiling and caching it on
+    /// first use.
+    pub fn get(&self, pattern: &str) -> Result<Regex, regex::Error> {
+        if let Some(re) = self.lock().regexes.get(pattern) {
+            return Ok(re.clone());
+        }
+
+        // Compile without holding the lock; a racing thread may insert first.
+        let re = Regex::new(pattern)?;
+        if self.capacity == 0 {
+            return Ok(re);
+        }
+        let mut state = self.lock();
+        if let Some(existing) = state.regexes.get(pattern) {
+            return Ok(existing.clone());
+        }
+        if state.regexes.len() >= self.capacity {
+            if let Some(oldest) = state.order.pop_front() {
+                state.regexes.remove(&oldest);
+            }
+        }
+        state.order.push_back(pattern.to_string());
+        state.regexes.insert(pattern.to_string(), re.clone());
+        Ok(re)
+    }
+
+    /// Returns the number of cached patterns.
+    pub fn len(&self) -> usize {
+        self.lock().regexes.len()
+    }
+
+    /// Returns `true` if no patterns are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every cached pattern.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.regexes.clear();
+        state.order.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // The state is always consistent between statements, so a panic in
+        // another thread cannot leave it half-updated.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reuses_compiled_regex() {
+        let cache = RegexCache::new();
+        let a = cache.get(r"\d+").unwrap();
+        let b = cache.get(r"\d+").unwrap();
+        assert_eq!(a.as_str(), b.as_str());
+        assert_eq!(cache.len(), 1);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_evicts_oldest() {
+        let cache = RegexCache::with_capacity(2);
+        for pattern in ["a", "b", "a", "c"] {
+            cache.get(pattern).unwrap();
+        }
+        let state = cache.lock();
+        let mut cached: Vec<&str> = state.regexes.keys().map(String::as_str).collect();
+        cached.sort_unstable();
+        assert_eq!(cached, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_zero_capacity_does_not_cache() {
+        let cache = RegexCache::with_capacity(0);
+        assert!(cache.get("x").unwrap().is_match("x"));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        let cache = RegexCache::new();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| assert!(cache.get("[a-z]+").unwrap().is_match("abc")));
+            }
+        });
+        assert_eq!(cache.len(), 1);
+    }
+}
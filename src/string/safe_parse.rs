@@ -27,6 +27,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_parse_float() {
         assert_eq!(safe_parse::<f64>("3.14"), Some(3.14));
 
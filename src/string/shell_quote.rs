@@ -0,0 +1,48 @@
+/// Quotes a string so a POSIX shell reads it back as a single word.
+///
+/// Strings made only of characters that are never special to the shell are
+/// returned unchanged; anything else is wrapped in single quotes, with embedded
+/// `'` written as `'\''`. The empty string becomes `''`. This is the inverse
+/// of [`shell_split`](crate::shell_split).
+///
+/// # Example
+/// ```rust
+/// use lo_::shell_quote;
+///
+/// assert_eq!(shell_quote("report.txt"), "report.txt");
+/// assert_eq!(shell_quote("my file.txt"), "'my file.txt'");
+/// assert_eq!(shell_quote("it's $HOME"), r"'it'\''s $HOME'");
+/// assert_eq!(shell_quote(""), "''");
+///
+/// ```
+pub fn shell_quote(s: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "_@%+=:,./-".contains(c);
+    if !s.is_empty() && s.chars().all(safe) {
+        return s.to_string();
+    }
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::shell_split;
+
+    #[test]
+    fn test_round_trips_through_shell_split() {
+        let args = [
+            "plain",
+            "",
+            "two words",
+            "it's",
+            "$(rm -rf /)",
+            "a\nb",
+            "\\",
+            "\"q\"",
+            "*",
+        ];
+        let line = args.map(shell_quote).join(" ");
+        let parsed = shell_split(&line).unwrap();
+        assert_eq!(parsed, args);
+    }
+}
@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by [`shell_split`] and [`csv_split`](crate::csv_split).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitError {
+    /// A quote opened at byte `position` is never closed.
+    UnterminatedQuote { position: usize },
+    /// The input ends with an unescaped backslash.
+    TrailingBackslash,
+    /// A closing quote at byte `position` is followed by more text instead of a delimiter.
+    TextAfterQuote { position: usize },
+}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SplitError::UnterminatedQuote { position } => {
+                write!(f, "quote at byte {position} is never closed")
+            }
+            SplitError::TrailingBackslash => write!(f, "input ends with a backslash"),
+            SplitError::TextAfterQuote { position } => {
+                write!(f, "unexpected text after the quote at byte {position}")
+            }
+        }
+    }
+}
+
+impl Error for SplitError {}
+
+/// Splits a string into words the way a POSIX shell does.
+///
+/// Words are separated by whitespace. Single quotes keep everything literally,
+/// double quotes keep everything but `\"`, `\\`, `` \` `` and `\$` escapes, and
+/// a backslash outside quotes escapes the next character.
+///
+/// Words without quotes or escapes are borrowed from the input.
+///
+/// # Example
+/// ```rust
+/// use lo_::shell_split;
+///
+/// let words = shell_split(r#"git commit -m "fix: it's done" --author='A B' a\ b"#).unwrap();
+/// assert_eq!(words, vec!["git", "commit", "-m", "fix: it's done", "--author=A B", "a b"]);
+///
+/// assert!(shell_split("echo 'oops").is_err());
+///
+/// ```
+pub fn shell_split(s: &str) -> Result<Vec<Cow<'_, str>>, SplitError> {
+    let mut words = Vec::new();
+    // The start of the current word, and its unescaped text once it differs from the input.
+    let mut word: Option<(usize, Option<String>)> = None;
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c.is_whitespace() {
+            if let Some((start, owned)) = word.take() {
+                words.push(finish(s, start, i, owned));
+            }
+            continue;
+        }
+
+        // A line continuation joins the text around it without starting a word.
+        if c == '\\' && chars.next_if(|&(_, next)| next == '\n').is_some() {
+            if let Some((start, owned)) = &mut word {
+                owned.get_or_insert_with(|| s[*start..i].to_string());
+            }
+            continue;
+        }
+
+        let (start, owned) = word.get_or_insert((i, None));
+        if c != '\'' && c != '"' && c != '\\' {
+            if let Some(owned) = owned {
+                owned.push(c);
+            }
+            continue;
+        }
+
+        let owned = owned.get_or_insert_with(|| s[*start..i].to_string());
+        match c {
+            '\\' => match chars.next() {
+                Some((_, escaped)) => owned.push(escaped),
+                None => return Err(SplitError::TrailingBackslash),
+            },
+            '\'' => loop {
+                match chars.next() {
+                    Some((_, '\'')) => break,
+                    Some((_, quoted)) => owned.push(quoted),
+                    None => return Err(SplitError::UnterminatedQuote { position: i }),
+                }
+            },
+            _ => loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    Some((_, '\\')) => match chars.peek() {
+                        Some((_, '\n')) => {
+                            chars.next();
+                        }
+                        Some((_, next @ ('"' | '\\' | '`' | '$'))) => {
+                            owned.push(*next);
+                            chars.next();
+                        }
+                        _ => owned.push('\\'),
+                    },
+                    Some((_, quoted)) => owned.push(quoted),
+                    None => return Err(SplitError::UnterminatedQuote { position: i }),
+                }
+            },
+        }
+    }
+
+    if let Some((start, owned)) = word {
+        words.push(finish(s, start, s.len(), owned));
+    }
+    Ok(words)
+}
+
+fn finish(s: &str, start: usize, end: usize, owned: Option<String>) -> Cow<'_, str> {
+    match owned {
+        Some(owned) => Cow::Owned(owned),
+        None => Cow::Borrowed(&s[start..end]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_words_are_borrowed() {
+        let words = shell_split("  ls -la\t/tmp \n").unwrap();
+        assert_eq!(words, vec!["ls", "-la", "/tmp"]);
+        assert!(words.iter().all(|w| matches!(w, Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn test_quotes_and_escapes() {
+        let words = shell_split(r#"'single \n' "double \"q\" \n $" a\"b "" x'y'z"#).unwrap();
+        assert_eq!(
+            words,
+            vec![r"single \n", r#"double "q" \n $"#, "a\"b", "", "xyz"]
+        );
+        assert!(matches!(words[0], Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_line_continuation() {
+        assert_eq!(shell_split("a\\\nb \"c\\\nd\"").unwrap(), vec!["ab", "cd"]);
+        assert_eq!(shell_split("a \\\n b").unwrap(), vec!["a", "b"]);
+        assert_eq!(shell_split("\\\n").unwrap(), Vec::<Cow<str>>::new());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            shell_split("echo \"open"),
+            Err(SplitError::UnterminatedQuote { position: 5 })
+        );
+        assert_eq!(shell_split("echo \\"), Err(SplitError::TrailingBackslash));
+        assert_eq!(
+            SplitError::UnterminatedQuote { position: 5 }.to_string(),
+            "quote at byte 5 is never closed"
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(shell_split("").unwrap().is_empty());
+        assert!(shell_split("   ").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,441 @@
+use crate::string::deburr;
+use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
+
+/// The string metrics supported by [`similarity_with`] and [`closest_match_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Metric {
+    /// Insertions, deletions and substitutions, see [`levenshtein`].
+    Levenshtein,
+    /// Like Levenshtein, plus transpositions of adjacent characters, see [`damerau_levenshtein`].
+    DamerauLevenshtein,
+    /// Matching characters and transpositions with a bonus for a common prefix, see [`jaro_winkler`].
+    #[default]
+    JaroWinkler,
+    /// Shared character bigrams, see [`sorensen_dice`].
+    SorensenDice,
+    /// Longest common subsequence, see [`lcs_distance`].
+    Lcs,
+}
+
+/// Options for [`similarity_with`] and [`closest_match_with`].
+///
+/// # Example
+/// ```rust
+/// use lo_::{similarity_with, Metric, SimilarityOptions};
+///
+/// let options = SimilarityOptions::new(Metric::Levenshtein)
+///     .ignore_case(true)
+///     .ignore_accents(true);
+/// assert_eq!(similarity_with("Zoë", "zoe", &options), 1.0);
+///
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SimilarityOptions {
+    metric: Metric,
+    ignore_case: bool,
+    ignore_accents: bool,
+}
+
+impl SimilarityOptions {
+    /// Creates options comparing with `metric`, case- and accent-sensitive.
+    pub fn new(metric: Metric) -> Self {
+        Self {
+            metric,
+            ..Self::default()
+        }
+    }
+
+    /// Compares case-insensitively (default `false`).
+    pub fn ignore_case(mut self, enabled: bool) -> Self {
+        self.ignore_case = enabled;
+        self
+    }
+
+    /// Strips accents with [`deburr`] before comparing (default `false`).
+    pub fn ignore_accents(mut self, enabled: bool) -> Self {
+        self.ignore_accents = enabled;
+        self
+    }
+
+    fn chars(&self, s: &str) -> Vec<char> {
+        let s = if self.ignore_accents {
+            deburr(s)
+        } else {
+            s.to_string()
+        };
+        if self.ignore_case {
+            s.nfc().flat_map(char::to_lowercase).collect()
+        } else {
+            s.nfc().collect()
+        }
+    }
+}
+
+/// Returns the Levenshtein distance: the number of single-character insertions,
+/// deletions and substitutions needed to turn `a` into `b`.
+///
+/// Strings are compared by Unicode scalar values after NFC normalization.
+///
+/// # Example
+/// ```rust
+/// use lo_::levenshtein;
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("café", "cafe"), 1);
+///
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    levenshtein_chars(&nfc(a), &nfc(b))
+}
+
+/// Returns the Damerau–Levenshtein distance: like [`levenshtein`], but swapping
+/// two adjacent characters counts as a single edit.
+///
+/// # Example
+/// ```rust
+/// use lo_::{damerau_levenshtein, levenshtein};
+/// assert_eq!(damerau_levenshtein("teh", "the"), 1);
+/// assert_eq!(levenshtein("teh", "the"), 2);
+///
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    damerau_levenshtein_chars(&nfc(a), &nfc(b))
+}
+
+/// Returns the Jaro–Winkler similarity between `0.0` (nothing in common) and `1.0` (equal).
+///
+/// # Example
+/// ```rust
+/// use lo_::jaro_winkler;
+/// assert!((jaro_winkler("martha", "marhta") - 0.961).abs() < 0.001);
+/// assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+///
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    jaro_winkler_chars(&nfc(a), &nfc(b))
+}
+
+/// Returns the Sørensen–Dice coefficient of the character bigrams of both strings,
+/// between `0.0` and `1.0`.
+///
+/// # Example
+/// ```rust
+/// use lo_::sorensen_dice;
+/// assert_eq!(sorensen_dice("night", "nacht"), 0.25);
+/// assert_eq!(sorensen_dice("night", "night"), 1.0);
+///
+/// ```
+pub fn sorensen_dice(a: &str, b: &str) -> f64 {
+    sorensen_dice_chars(&nfc(a), &nfc(b))
+}
+
+/// Returns the longest common subsequence distance: the number of characters
+/// that must be deleted from either string so that both become equal.
+///
+/// # Example
+/// ```rust
+/// use lo_::lcs_distance;
+/// assert_eq!(lcs_distance("abcde", "ace"), 2);
+/// assert_eq!(lcs_distance("abc", "xbz"), 4);
+///
+/// ```
+pub fn lcs_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (nfc(a), nfc(b));
+    a.len() + b.len() - 2 * lcs_len(&a, &b)
+}
+
+/// Returns a similarity between `0.0` and `1.0` using the given [`Metric`].
+///
+/// Edit distances are normalized by the length of the longer string, and the
+/// LCS distance by the combined length of both.
+///
+/// # Example
+/// ```rust
+/// use lo_::{similarity, Metric};
+/// assert_eq!(similarity("kitten", "sitting", Metric::Levenshtein), 1.0 - 3.0 / 7.0);
+/// assert_eq!(similarity("", "", Metric::SorensenDice), 1.0);
+///
+/// ```
+pub fn similarity(a: &str, b: &str, metric: Metric) -> f64 {
+    similarity_with(a, b, &SimilarityOptions::new(metric))
+}
+
+/// Returns a similarity between `0.0` and `1.0` according to [`SimilarityOptions`].
+pub fn similarity_with(a: &str, b: &str, options: &SimilarityOptions) -> f64 {
+    let (a, b) = (options.chars(a), options.chars(b));
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    match options.metric {
+        Metric::Levenshtein => 1.0 - levenshtein_chars(&a, &b) as f64 / longest as f64,
+        Metric::DamerauLevenshtein => {
+            1.0 - damerau_levenshtein_chars(&a, &b) as f64 / longest as f64
+        }
+        Metric::JaroWinkler => jaro_winkler_chars(&a, &b),
+        Metric::SorensenDice => sorensen_dice_chars(&a, &b),
+        Metric::Lcs => 2.0 * lcs_len(&a, &b) as f64 / (a.len() + b.len()) as f64,
+    }
+}
+
+/// Returns the candidate most similar to `needle` by Jaro–Winkler similarity,
+/// if it scores at least `threshold`. Ties go to the earliest candidate.
+///
+/// # Example
+/// ```rust
+/// use lo_::closest_match;
+///
+/// let commands = ["build", "bench", "check", "clean"];
+/// assert_eq!(closest_match("biuld", &commands, 0.8), Some("build"));
+/// assert_eq!(closest_match("deploy", &commands, 0.8), None);
+///
+/// ```
+pub fn closest_match<'a, S: AsRef<str>>(
+    needle: &str,
+    candidates: &'a [S],
+    threshold: f64,
+) -> Option<&'a str> {
+    closest_match_with(needle, candidates, threshold, &SimilarityOptions::default())
+}
+
+/// Like [`closest_match`], with the metric and normalization from [`SimilarityOptions`].
+///
+/// # Example
+/// ```rust
+/// use lo_::{closest_match_with, Metric, SimilarityOptions};
+///
+/// let names = ["Zoë", "Chloé", "Joe"];
+/// let options = SimilarityOptions::new(Metric::Levenshtein)
+///     .ignore_case(true)
+///     .ignore_accents(true);
+/// assert_eq!(closest_match_with("CHLOE", &names, 0.9, &options), Some("Chloé"));
+///
+/// ```
+pub fn closest_match_with<'a, S: AsRef<str>>(
+    needle: &str,
+    candidates: &'a [S],
+    threshold: f64,
+    options: &SimilarityOptions,
+) -> Option<&'a str> {
+    let mut best: Option<(&'a str, f64)> = None;
+    for candidate in candidates {
+        let candidate = candidate.as_ref();
+        let score = similarity_with(needle, candidate, options);
+        if score >= threshold && best.is_none_or(|(_, s)| score > s) {
+            best = Some((candidate, score));
+        }
+    }
+    best.map(|(candidate, _)| candidate)
+}
+
+fn nfc(s: &str) -> Vec<char> {
+    s.nfc().collect()
+}
+
+fn levenshtein_chars(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The unrestricted Damerau–Levenshtein distance (Lowrance–Wagner algorithm).
+fn damerau_levenshtein_chars(a: &[char], b: &[char]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let max = n + m;
+    let width = m + 2;
+    let mut d = vec![0; (n + 2) * width];
+    d[0] = max;
+    for i in 0..=n {
+        d[(i + 1) * width] = max;
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=m {
+        d[j + 1] = max;
+        d[width + j + 1] = j;
+    }
+
+    let mut last_row: HashMap<char, usize> = HashMap::new();
+    for i in 1..=n {
+        let mut last_col = 0;
+        for j in 1..=m {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_col = j;
+                0
+            } else {
+                1
+            };
+            d[(i + 1) * width + j + 1] = (d[i * width + j] + cost)
+                .min(d[(i + 1) * width + j] + 1)
+                .min(d[i * width + j + 1] + 1)
+                .min(d[k * width + l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    d[(n + 1) * width + m + 1]
+}
+
+fn jaro_winkler_chars(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_used = vec![false; b.len()];
+    let mut a_matches = Vec::new();
+
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_used[j] && b[j] == *ca {
+                b_used[j] = true;
+                a_matches.push(*ca);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b.iter().zip(&b_used).filter(|(_, used)| **used);
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(x, (y, _))| x != y)
+        .count()
+        / 2;
+
+    let m = a_matches.len() as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0;
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn sorensen_dice_chars(a: &[char], b: &[char]) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    if a.len() < 2 || b.len() < 2 {
+        return 0.0;
+    }
+
+    let mut bigrams: HashMap<(char, char), usize> = HashMap::new();
+    for pair in a.windows(2) {
+        *bigrams.entry((pair[0], pair[1])).or_default() += 1;
+    }
+    let mut shared = 0;
+    for pair in b.windows(2) {
+        if let Some(count) = bigrams.get_mut(&(pair[0], pair[1])).filter(|c| **c > 0) {
+            *count -= 1;
+            shared += 1;
+        }
+    }
+    2.0 * shared as f64 / (a.len() + b.len() - 2) as f64
+}
+
+fn lcs_len(a: &[char], b: &[char]) -> usize {
+    let mut row = vec![0; b.len() + 1];
+    for ca in a {
+        let mut diagonal = 0;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("日本語", "日本"), 1);
+        // Composed and decomposed forms are equal.
+        assert_eq!(levenshtein("e\u{301}", "\u{e9}"), 0);
+    }
+
+    #[test]
+    fn test_damerau_levenshtein() {
+        assert_eq!(damerau_levenshtein("ca", "abc"), 2);
+        assert_eq!(damerau_levenshtein("abcdef", "abcfed"), 2);
+        assert_eq!(damerau_levenshtein("", "ab"), 2);
+        assert_eq!(damerau_levenshtein("ab", "ba"), 1);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        assert!((jaro_winkler("dixon", "dicksonx") - 0.813).abs() < 0.001);
+        assert!((jaro_winkler("dwayne", "duane") - 0.84).abs() < 0.001);
+        assert_eq!(jaro_winkler("same", "same"), 1.0);
+        assert_eq!(jaro_winkler("", "a"), 0.0);
+    }
+
+    #[test]
+    fn test_sorensen_dice_and_lcs() {
+        assert_eq!(sorensen_dice("aa", "aaaa"), 0.5);
+        assert_eq!(sorensen_dice("a", "b"), 0.0);
+        assert_eq!(lcs_distance("", "abc"), 3);
+        assert_eq!(similarity("abcde", "ace", Metric::Lcs), 0.75);
+    }
+
+    #[test]
+    fn test_similarity_is_normalized() {
+        let metrics = [
+            Metric::Levenshtein,
+            Metric::DamerauLevenshtein,
+            Metric::JaroWinkler,
+            Metric::SorensenDice,
+            Metric::Lcs,
+        ];
+        for metric in metrics {
+            assert_eq!(similarity("same", "same", metric), 1.0);
+            assert_eq!(similarity("", "", metric), 1.0);
+            assert_eq!(similarity("abc", "xyz", metric), 0.0);
+            let score = similarity("hello", "help", metric);
+            assert!(score > 0.0 && score < 1.0, "{metric:?}: {score}");
+        }
+    }
+
+    #[test]
+    fn test_case_and_accent_insensitive() {
+        let strict = SimilarityOptions::new(Metric::Levenshtein);
+        assert!(similarity_with("Crème Brûlée", "creme brulee", &strict) < 1.0);
+
+        let loose = strict.ignore_case(true).ignore_accents(true);
+        assert_eq!(similarity_with("Crème Brûlée", "creme brulee", &loose), 1.0);
+        assert_eq!(similarity_with("Straße", "strasse", &loose), 1.0);
+    }
+
+    #[test]
+    fn test_closest_match_ties_and_threshold() {
+        let candidates = vec!["cat".to_string(), "bat".to_string(), "rat".to_string()];
+        let options = SimilarityOptions::new(Metric::Levenshtein);
+        assert_eq!(
+            closest_match_with("hat", &candidates, 0.5, &options),
+            Some("cat")
+        );
+        assert_eq!(closest_match_with("dog", &candidates, 0.5, &options), None);
+        assert_eq!(closest_match("x", &[] as &[&str], 0.0), None);
+    }
+}
@@ -0,0 +1,290 @@
+use crate::string::{slugify_with, SlugOptions};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+type ExistsFn<'a> = dyn Fn(&str) -> bool + 'a;
+
+/// How many suffixed candidates [`SlugRegistry::issue`] tries before giving up.
+const MAX_ATTEMPTS: usize = 10_000;
+
+/// Error returned by [`SlugRegistry::issue`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlugRegistryError {
+    /// The suffix format has no `{n}` placeholder, so every suffix would be the same.
+    MissingCounter,
+    /// Every candidate up to the given number of attempts was taken.
+    Exhausted { attempts: usize },
+    /// The input has no letters or digits, so its slug would be empty.
+    EmptySlug,
+    /// The suffix leaves no room for the base slug within `max_length`.
+    SuffixTooLong { max_length: usize },
+}
+
+impl fmt::Display for SlugRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlugRegistryError::MissingCounter => {
+                write!(f, "suffix format has no {{n}} placeholder")
+            }
+            SlugRegistryError::Exhausted { attempts } => {
+                write!(f, "no free slug after {attempts} attempts")
+            }
+            SlugRegistryError::EmptySlug => write!(f, "input has no characters to slugify"),
+            SlugRegistryError::SuffixTooLong { max_length } => {
+                write!(
+                    f,
+                    "suffix leaves no room for the slug within {max_length} chars"
+                )
+            }
+        }
+    }
+}
+
+impl Error for SlugRegistryError {}
+
+/// Issues unique slugs, appending a numeric suffix (`-2`, `-3`, …) on collisions.
+///
+/// Slugs are built with [`slugify_with`]. Every slug the registry issues is
+/// remembered in memory; an optional `exists` callback can additionally look
+/// slugs up elsewhere, e.g. in a database.
+///
+/// # Examples
+/// ```rust
+/// use lo_::SlugRegistry;
+///
+/// let mut registry = SlugRegistry::new();
+/// assert_eq!(registry.issue("Hello World").unwrap(), "hello-world");
+/// assert_eq!(registry.issue("Hello, World!").unwrap(), "hello-world-2");
+/// assert_eq!(registry.issue("hello world").unwrap(), "hello-world-3");
+///
+/// let stored = ["my-post", "my-post-2"];
+/// let mut registry = SlugRegistry::new().exists(|slug| stored.contains(&slug));
+/// assert_eq!(registry.issue("My Post").unwrap(), "my-post-3");
+///
+/// ```
+pub struct SlugRegistry<'a> {
+    options: SlugOptions,
+    suffix_format: String,
+    taken: HashSet<String>,
+    exists: Option<Box<ExistsFn<'a>>>,
+}
+
+impl Default for SlugRegistry<'_> {
+    fn default() -> Self {
+        Self {
+            options: SlugOptions::default(),
+            suffix_format: "-{n}".to_string(),
+            taken: HashSet::new(),
+            exists: None,
+        }
+    }
+}
+
+impl<'a> SlugRegistry<'a> {
+    /// Creates an empty registry using the default [`SlugOptions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the options used to build the base slug. A length limit set
+    /// earlier with [`max_length`](Self::max_length) is kept unless `options`
+    /// has its own.
+    pub fn options(mut self, options: SlugOptions) -> Self {
+        let max_length = options.max_length.or(self.options.max_length);
+        self.options = options;
+        self.options.max_length = max_length;
+        self
+    }
+
+    /// Sets the suffix appended on collisions; `{n}` is replaced by the counter,
+    /// which starts at 2 (default `-{n}`). A format without `{n}` makes
+    /// [`issue`](Self::issue) fail with [`SlugRegistryError::MissingCounter`].
+    pub fn suffix_format(mut self, format: &str) -> Self {
+        self.suffix_format = format.to_string();
+        self
+    }
+
+    /// Limits issued slugs, suffix included, to `max_length` characters.
+    /// The base slug is shortened on a word boundary to make room for the suffix.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.options.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets a callback that reports slugs taken outside of this registry.
+    pub fn exists<F>(mut self, exists: F) -> Self
+    where
+        F: Fn(&str) -> bool + 'a,
+    {
+        self.exists = Some(Box::new(exists));
+        self
+    }
+
+    /// Marks a slug as taken without issuing it.
+    pub fn reserve(&mut self, slug: &str) {
+        self.taken.insert(slug.to_string());
+    }
+
+    /// Returns true if the slug was issued or reserved, or the `exists` callback reports it.
+    pub fn is_taken(&self, slug: &str) -> bool {
+        self.taken.contains(slug) || self.exists.as_ref().is_some_and(|exists| exists(slug))
+    }
+
+    /// Returns a slug for `input` that is not taken yet, and records it as taken.
+    ///
+    /// When a length limit is set, the base slug is shortened so that the
+    /// suffix fits, but it always keeps at least one char.
+    ///
+    /// # Errors
+    /// Fails if the suffix format has no `{n}`, if `input` has no letters or
+    /// digits, if a suffix would leave no room for the base slug within the
+    /// length limit, or if the first 10,000 suffixed candidates are all taken,
+    /// e.g. because the `exists` callback always returns `true`.
+    pub fn issue(&mut self, input: &str) -> Result<String, SlugRegistryError> {
+        if !self.suffix_format.contains("{n}") {
+            return Err(SlugRegistryError::MissingCounter);
+        }
+        let base = slugify_with(input, &self.options);
+        if base.is_empty() {
+            return Err(SlugRegistryError::EmptySlug);
+        }
+        if !self.is_taken(&base) {
+            self.taken.insert(base.clone());
+            return Ok(base);
+        }
+
+        for n in 2..MAX_ATTEMPTS + 2 {
+            let suffix = self.suffix_format.replace("{n}", &n.to_string());
+            let shortened = self.base_for_suffix(input, &base, &suffix);
+            if shortened.is_empty() {
+                return Err(SlugRegistryError::SuffixTooLong {
+                    max_length: self.options.max_length.unwrap_or_default(),
+                });
+            }
+            let candidate = format!("{shortened}{suffix}");
+            if !self.is_taken(&candidate) {
+                self.taken.insert(candidate.clone());
+                return Ok(candidate);
+            }
+        }
+        Err(SlugRegistryError::Exhausted {
+            attempts: MAX_ATTEMPTS,
+        })
+    }
+
+    /// Returns the base slug shortened so that the suffix still fits the length limit.
+    fn base_for_suffix(&self, input: &str, base: &str, suffix: &str) -> String {
+        let Some(max) = self.options.max_length else {
+            return base.to_string();
+        };
+
+        let room = max.saturating_sub(suffix.chars().count());
+        if base.chars().count() <= room {
+            return base.to_string();
+        }
+        slugify_with(input, &self.options.clone().max_length(room))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_unique() {
+        let mut registry = SlugRegistry::new();
+        assert_eq!(registry.issue("Rust").unwrap(), "rust");
+        assert_eq!(registry.issue("Go").unwrap(), "go");
+        assert_eq!(registry.issue("RUST").unwrap(), "rust-2");
+        assert_eq!(registry.issue("rust!").unwrap(), "rust-3");
+    }
+
+    #[test]
+    fn test_reserve_and_is_taken() {
+        let mut registry = SlugRegistry::new();
+        registry.reserve("about");
+        assert!(registry.is_taken("about"));
+        assert!(!registry.is_taken("contact"));
+        assert_eq!(registry.issue("About").unwrap(), "about-2");
+    }
+
+    #[test]
+    fn test_exists_callback() {
+        let existing = ["news".to_string(), "news-2".to_string()];
+        let mut registry = SlugRegistry::new().exists(|slug| existing.iter().any(|s| s == slug));
+        assert_eq!(registry.issue("News").unwrap(), "news-3");
+        assert_eq!(registry.issue("News").unwrap(), "news-4");
+    }
+
+    #[test]
+    fn test_suffix_format() {
+        let mut registry = SlugRegistry::new().suffix_format("_v{n}");
+        assert_eq!(registry.issue("draft").unwrap(), "draft");
+        assert_eq!(registry.issue("draft").unwrap(), "draft_v2");
+    }
+
+    #[test]
+    fn test_max_length_leaves_room_for_suffix() {
+        let mut registry = SlugRegistry::new().max_length(12);
+        assert_eq!(registry.issue("hello world again").unwrap(), "hello-world");
+        assert_eq!(registry.issue("hello world again").unwrap(), "hello-2");
+        assert_eq!(registry.issue("extraordinary").unwrap(), "extraordinar");
+        assert_eq!(registry.issue("extraordinary").unwrap(), "extraordin-2");
+    }
+
+    #[test]
+    fn test_options_keep_max_length() {
+        let mut registry = SlugRegistry::new()
+            .max_length(5)
+            .options(SlugOptions::new().separator("_"));
+        assert_eq!(registry.issue("hello world").unwrap(), "hello");
+
+        let options = SlugOptions::new().max_length(8);
+        let mut registry = SlugRegistry::new().max_length(5).options(options);
+        assert_eq!(registry.issue("hello world").unwrap(), "hello");
+        assert_eq!(registry.issue("hello world").unwrap(), "hello-2");
+    }
+
+    #[test]
+    fn test_base_is_never_dropped() {
+        let mut registry = SlugRegistry::new().max_length(3);
+        assert_eq!(registry.issue("hello").unwrap(), "hel");
+        assert_eq!(registry.issue("hello").unwrap(), "h-2");
+
+        let mut registry = SlugRegistry::new().max_length(2);
+        assert_eq!(registry.issue("hello").unwrap(), "he");
+        assert_eq!(
+            registry.issue("hello"),
+            Err(SlugRegistryError::SuffixTooLong { max_length: 2 })
+        );
+
+        let mut registry = SlugRegistry::new();
+        assert_eq!(registry.issue("!!!"), Err(SlugRegistryError::EmptySlug));
+    }
+
+    #[test]
+    fn test_errors_instead_of_looping() {
+        let mut registry = SlugRegistry::new().suffix_format("-copy");
+        assert_eq!(
+            registry.issue("draft"),
+            Err(SlugRegistryError::MissingCounter)
+        );
+
+        let mut registry = SlugRegistry::new().exists(|_| true);
+        assert_eq!(
+            registry.issue("draft"),
+            Err(SlugRegistryError::Exhausted {
+                attempts: MAX_ATTEMPTS
+            })
+        );
+    }
+
+    #[test]
+    fn test_custom_options() {
+        let options = SlugOptions::new().separator("_").transliterate(true);
+        let mut registry = SlugRegistry::new().options(options);
+        assert_eq!(registry.issue("Straße").unwrap(), "strasse");
+        assert_eq!(registry.issue("Strasse").unwrap(), "strasse-2");
+    }
+}
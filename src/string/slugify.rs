@@ -1,3 +1,93 @@
+use crate::string::transliterate;
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// Options for [`slugify_with`].
+///
+/// The default options produce the same output as [`slugify`]: lowercase words
+/// joined by `-`, with non-Latin letters kept as they are.
+///
+/// # Example
+/// ```rust
+/// use lo_::{slugify_with, SlugOptions};
+///
+/// let options = SlugOptions::new()
+///     .transliterate(true)
+///     .separator("_")
+///     .replacement("&", "and")
+///     .stop_words(["the", "a"])
+///     .max_length(24);
+///
+/// assert_eq!(slugify_with("The Straße & a Café", &options), "strasse_and_cafe");
+///
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlugOptions {
+    separator: String,
+    pub(crate) max_length: Option<usize>,
+    transliterate: bool,
+    stop_words: HashSet<String>,
+    replacements: Vec<(String, String)>,
+}
+
+impl Default for SlugOptions {
+    fn default() -> Self {
+        Self {
+            separator: "-".to_string(),
+            max_length: None,
+            transliterate: false,
+            stop_words: HashSet::new(),
+            replacements: Vec::new(),
+        }
+    }
+}
+
+impl SlugOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the string placed between words (default `-`).
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Limits the slug to `max_length` characters, cutting on a word boundary.
+    /// A single word longer than the limit is cut at the limit.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Converts letters to ASCII with [`transliterate`] and drops anything
+    /// that is still not ASCII afterwards.
+    pub fn transliterate(mut self, enabled: bool) -> Self {
+        self.transliterate = enabled;
+        self
+    }
+
+    /// Removes the given words (compared case-insensitively) from the slug.
+    pub fn stop_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.stop_words
+            .extend(words.into_iter().map(|w| w.as_ref().to_lowercase()));
+        self
+    }
+
+    /// Replaces `from` with `to` before slugifying, e.g. `&` with `and`.
+    /// The replacement is treated as a separate word.
+    pub fn replacement(mut self, from: &str, to: &str) -> Self {
+        self.replacements.push((from.to_string(), to.to_string()));
+        self
+    }
+}
+
 /// Converts a string to a URL-friendly slug format.
 ///
 /// # Examples
@@ -10,17 +100,90 @@
 ///
 /// ```
 pub fn slugify(input: &str) -> String {
-    use regex::Regex;
+    slugify_with(input, &SlugOptions::default())
+}
+
+/// Converts a string to a URL-friendly slug using the given [`SlugOptions`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lo_::{slugify_with, SlugOptions};
+/// let options = SlugOptions::new().transliterate(true);
+/// assert_eq!(slugify_with("Привет, мир!", &options), "privet-mir");
+/// assert_eq!(slugify_with("Øresund Bridge", &options), "oresund-bridge");
+///
+/// let options = SlugOptions::new().max_length(12);
+/// assert_eq!(slugify_with("Rust is blazing fast", &options), "rust-is");
+///
+/// ```
+pub fn slugify_with(input: &str, options: &SlugOptions) -> String {
+    let mut text = input.to_string();
+    for (from, to) in &options.replacements {
+        if !from.is_empty() {
+            text = text.replace(from.as_str(), &format!(" {to} "));
+        }
+    }
+
+    if options.transliterate {
+        text = transliterate(&text);
+    }
+    let text = text.to_lowercase();
+
+    let re = if options.transliterate {
+        ascii_word_regex()
+    } else {
+        word_regex()
+    };
+
+    let words = re
+        .find_iter(&text)
+        .map(|m| m.as_str())
+        .filter(|w| !options.stop_words.contains(*w));
 
-    // Replace non-alphanumeric characters with dashes
-    let re_non_alnum = Regex::new(r"[^\p{L}\p{N}]+").unwrap(); // Unicode aware
-    let re_trim_dashes = Regex::new(r"^-+|-+$").unwrap();
+    join_words(words, &options.separator, options.max_length)
+}
+
+/// Joins words with the separator, stopping before the first word that would exceed `max_length`.
+fn join_words<'a, I>(words: I, separator: &str, max_length: Option<usize>) -> String
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut slug = String::new();
+    let mut len = 0;
+    let sep_len = separator.chars().count();
+
+    for word in words {
+        let word_len = word.chars().count();
+        let extra = if slug.is_empty() { 0 } else { sep_len };
+
+        if let Some(max) = max_length {
+            if len + extra + word_len > max {
+                if slug.is_empty() {
+                    slug.extend(word.chars().take(max));
+                }
+                break;
+            }
+        }
+
+        if !slug.is_empty() {
+            slug.push_str(separator);
+        }
+        slug.push_str(word);
+        len += extra + word_len;
+    }
+
+    slug
+}
 
-    let slug = input.to_lowercase().trim().to_string();
-    let slug = re_non_alnum.replace_all(&slug, "-");
-    let slug = re_trim_dashes.replace_all(&slug, "");
+fn word_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"[\p{L}\p{N}]+").unwrap()) // Unicode aware
+}
 
-    slug.to_string()
+fn ascii_word_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"[a-z0-9]+").unwrap())
 }
 
 #[cfg(test)]
@@ -56,4 +219,46 @@ mod tests {
     fn test_only_symbols() {
         assert_eq!(slugify("$$$%%%!!!"), "");
     }
+
+    #[test]
+    fn test_transliterate() {
+        let options = SlugOptions::new().transliterate(true);
+        assert_eq!(slugify_with("Café del Mar", &options), "cafe-del-mar");
+        assert_eq!(slugify_with("Große Straße", &options), "grosse-strasse");
+        assert_eq!(slugify_with("Αθήνα 2004", &options), "athina-2004");
+        assert_eq!(slugify_with("東京 Tokyo", &options), "tokyo");
+    }
+
+    #[test]
+    fn test_separator() {
+        let options = SlugOptions::new().separator("_");
+        assert_eq!(slugify_with("Hello World!", &options), "hello_world");
+
+        let options = SlugOptions::new().separator("");
+        assert_eq!(slugify_with("Hello World!", &options), "helloworld");
+    }
+
+    #[test]
+    fn test_max_length() {
+        let options = SlugOptions::new().max_length(11);
+        assert_eq!(slugify_with("hello world again", &options), "hello-world");
+
+        let options = SlugOptions::new().max_length(10);
+        assert_eq!(slugify_with("hello world again", &options), "hello");
+
+        let options = SlugOptions::new().max_length(4);
+        assert_eq!(slugify_with("extraordinary", &options), "extr");
+    }
+
+    #[test]
+    fn test_stop_words_and_replacements() {
+        let options = SlugOptions::new()
+            .stop_words(["The", "of"])
+            .replacement("&", "and");
+        assert_eq!(
+            slugify_with("The Lord of the Rings & Hobbit", &options),
+            "lord-rings-and-hobbit"
+        );
+        assert_eq!(slugify_with("rock&roll", &options), "rock-and-roll");
+    }
 }
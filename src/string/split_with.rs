@@ -0,0 +1,164 @@
+/// Options for [`split_with`].
+///
+/// # Example
+/// ```rust
+/// use lo_::{split_with, SplitOptions};
+///
+/// let options = SplitOptions::new(",").delimiter(";").limit(3);
+/// assert_eq!(split_with("a,b;c,d", &options), vec!["a", "b", "c,d"]);
+///
+/// let options = SplitOptions::new("/").limit(2).from_right(true);
+/// assert_eq!(split_with("usr/local/bin", &options), vec!["usr/local", "bin"]);
+///
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SplitOptions {
+    delimiters: Vec<String>,
+    limit: Option<usize>,
+    from_right: bool,
+    keep_delimiters: bool,
+}
+
+impl SplitOptions {
+    /// Creates options splitting on `delimiter`.
+    pub fn new(delimiter: &str) -> Self {
+        Self::default().delimiter(delimiter)
+    }
+
+    /// Adds another delimiter. Where several match, the longest one wins.
+    pub fn delimiter(mut self, delimiter: &str) -> Self {
+        if !delimiter.is_empty() {
+            self.delimiters.push(delimiter.to_string());
+        }
+        self
+    }
+
+    /// Returns at most `limit` pieces, like [`str::splitn`]; the last piece holds the remainder.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// With a limit, splits from the end like [`str::rsplitn`] (default `false`).
+    /// Pieces are still returned in input order.
+    pub fn from_right(mut self, enabled: bool) -> Self {
+        self.from_right = enabled;
+        self
+    }
+
+    /// Returns each matched delimiter as its own piece between the others (default `false`).
+    pub fn keep_delimiters(mut self, enabled: bool) -> Self {
+        self.keep_delimiters = enabled;
+        self
+    }
+}
+
+/// Splits a string according to [`SplitOptions`], borrowing every piece from the input.
+///
+/// # Example
+/// ```rust
+/// use lo_::{split_with, SplitOptions};
+///
+/// let options = SplitOptions::new("+").delimiter("-").keep_delimiters(true);
+/// assert_eq!(split_with("1+2-3", &options), vec!["1", "+", "2", "-", "3"]);
+///
+/// ```
+pub fn split_with<'a>(input: &'a str, options: &SplitOptions) -> Vec<&'a str> {
+    if options.limit == Some(0) {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i < input.len() {
+        let longest = options
+            .delimiters
+            .iter()
+            .filter(|d| input[i..].starts_with(d.as_str()))
+            .map(String::len)
+            .max();
+        match longest {
+            Some(len) => {
+                matches.push((i, i + len));
+                i += len;
+            }
+            None => i += input[i..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+
+    if let Some(limit) = options.limit {
+        let keep = matches.len().min(limit - 1);
+        if options.from_right {
+            matches.drain(..matches.len() - keep);
+        } else {
+            matches.truncate(keep);
+        }
+    }
+
+    let mut pieces = Vec::with_capacity(matches.len() * 2 + 1);
+    let mut start = 0;
+    for (from, to) in matches {
+        pieces.push(&input[start..from]);
+        if options.keep_delimiters {
+            pieces.push(&input[from..to]);
+        }
+        start = to;
+    }
+    pieces.push(&input[start..]);
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_str_split() {
+        let options = SplitOptions::new("::");
+        assert_eq!(split_with("a::b::::c", &options), vec!["a", "b", "", "c"]);
+        assert_eq!(split_with("", &options), vec![""]);
+    }
+
+    #[test]
+    fn test_multiple_delimiters_longest_wins() {
+        let options = SplitOptions::new("-").delimiter("->").delimiter(" ");
+        assert_eq!(split_with("a->b-c d", &options), vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_limits() {
+        let options = SplitOptions::new(",").limit(2);
+        assert_eq!(split_with("a,b,c", &options), vec!["a", "b,c"]);
+        assert_eq!(
+            split_with("a,b,c", &options.clone().from_right(true)),
+            vec!["a,b", "c"]
+        );
+        assert_eq!(
+            split_with("a,b,c", &options.clone().limit(10)),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(
+            split_with("a,b,c", &options.clone().limit(1)),
+            vec!["a,b,c"]
+        );
+        assert!(split_with("a,b,c", &options.limit(0)).is_empty());
+    }
+
+    #[test]
+    fn test_keep_delimiters_with_limit() {
+        let options = SplitOptions::new("=")
+            .limit(2)
+            .from_right(true)
+            .keep_delimiters(true);
+        assert_eq!(split_with("a=b=c", &options), vec!["a=b", "=", "c"]);
+    }
+
+    #[test]
+    fn test_no_delimiters() {
+        assert_eq!(split_with("a,b", &SplitOptions::default()), vec!["a,b"]);
+        assert_eq!(
+            split_with("héllo wörld", &SplitOptions::new("ö")),
+            vec!["héllo w", "rld"]
+        );
+    }
+}
@@ -41,30 +41,30 @@ mod tests {
     #[test]
     fn test_starts_with() {
         // Test case 1: The string starts with the target at position 0
-        assert_eq!(starts_with("abcdef", "abc"), true);
+        assert!(starts_with("abcdef", "abc"));
     }
 
     #[test]
     fn test_starts_with_position() {
         // Test case 1: The string starts with the target at position 0
-        assert_eq!(starts_with_position("abcdef", "abc", Some(0)), true);
+        assert!(starts_with_position("abcdef", "abc", Some(0)));
 
         // Test case 2: The string does not start with the target at position 2
-        assert_eq!(starts_with_position("abcdef", "def", Some(2)), false);
+        assert!(!starts_with_position("abcdef", "def", Some(2)));
 
         // Test case 3: The target is an empty string, so it should always return true
-        assert_eq!(starts_with_position("abcdef", "", Some(2)), true);
+        assert!(starts_with_position("abcdef", "", Some(2)));
 
         // Test case 4: The position is not provided, and it starts with the target at position 0
-        assert_eq!(starts_with_position("abcdef", "abc", None), true);
+        assert!(starts_with_position("abcdef", "abc", None));
 
         // Test case 5: The position is not provided, and it does not start with the target at position 2
-        assert_eq!(starts_with_position("abcdef", "cde", None), false);
+        assert!(!starts_with_position("abcdef", "cde", None));
 
         // Test case 6: The position is greater than or equal to the string length, so it should return false
-        assert_eq!(starts_with_position("abcdef", "abc", Some(10)), false);
+        assert!(!starts_with_position("abcdef", "abc", Some(10)));
 
         // Test case 7: The position is equal to the string length, and the target is an empty string, so it should return true
-        assert_eq!(starts_with_position("abcdef", "", Some(6)), true);
+        assert!(starts_with_position("abcdef", "", Some(6)));
     }
 }
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 /// Defines the padding type: Left, Right, or Center.
 /// Alignment options for padding strings.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -27,26 +29,69 @@ pub enum Alignment {
 ///
 /// ```
 pub fn str_pad(input: &str, length: usize, pad_str: &str, pad_type: Alignment) -> String {
+    str_pad_cow(input, length, pad_str, pad_type).into_owned()
+}
+
+/// Like [`str_pad`], but borrows the input when no padding is needed.
+///
+/// # Example
+/// ```rust
+/// use lo_::{str_pad_cow, Alignment};
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(str_pad_cow("12345", 5, "0", Alignment::Left), Cow::Borrowed(_)));
+/// assert_eq!(str_pad_cow("42", 5, "0", Alignment::Left), "00042");
+///
+/// ```
+pub fn str_pad_cow<'a>(
+    input: &'a str,
+    length: usize,
+    pad_str: &str,
+    pad_type: Alignment,
+) -> Cow<'a, str> {
+    if pad_str.is_empty() || input.chars().nth(length.saturating_sub(1)).is_some() {
+        return Cow::Borrowed(input);
+    }
+    let mut result = String::with_capacity(input.len() + length * pad_str.len());
+    str_pad_into(input, length, pad_str, pad_type, &mut result);
+    Cow::Owned(result)
+}
+
+/// Like [`str_pad`], but appends the result to `out` instead of allocating.
+///
+/// # Example
+/// ```rust
+/// use lo_::{str_pad_into, Alignment};
+///
+/// let mut out = String::from("#");
+/// str_pad_into("7", 3, "0", Alignment::Left, &mut out);
+/// assert_eq!(out, "#007");
+///
+/// ```
+pub fn str_pad_into(
+    input: &str,
+    length: usize,
+    pad_str: &str,
+    pad_type: Alignment,
+    out: &mut String,
+) {
     let input_len = input.chars().count();
     if length <= input_len || pad_str.is_empty() {
-        return input.to_string();
+        out.push_str(input);
+        return;
     }
 
     let pad_len = length - input_len;
-    let repeat_times = pad_len.div_ceil(pad_str.chars().count());
-    let full_pad: String = pad_str.repeat(repeat_times).chars().take(pad_len).collect();
+    let left_len = match pad_type {
+        Alignment::Left => pad_len,
+        Alignment::Right => 0,
+        Alignment::Center => pad_len / 2,
+    };
+    let pad = || pad_str.chars().cycle();
 
-    match pad_type {
-        Alignment::Left => format!("{full_pad}{input}"),
-        Alignment::Right => format!("{input}{full_pad}"),
-        Alignment::Center => {
-            let left_len = pad_len / 2;
-            let right_len = pad_len - left_len;
-            let left_pad: String = full_pad.chars().take(left_len).collect();
-            let right_pad: String = full_pad.chars().skip(left_len).take(right_len).collect();
-            format!("{left_pad}{input}{right_pad}")
-        }
-    }
+    out.extend(pad().take(left_len));
+    out.push_str(input);
+    out.extend(pad().skip(left_len).take(pad_len - left_len));
 }
 
 #[cfg(test)]
@@ -77,4 +122,24 @@ mod tests {
     fn test_empty_pad_str() {
         assert_eq!(str_pad("test", 10, "", Alignment::Right), "test");
     }
+
+    #[test]
+    fn test_str_pad_cow_and_into() {
+        assert!(matches!(
+            str_pad_cow("hello", 3, "_", Alignment::Left),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            str_pad_cow("test", 10, "", Alignment::Left),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(str_pad_cow("hi", 7, "ab", Alignment::Center), "abhiaba");
+
+        let mut out = String::with_capacity(32);
+        let capacity = out.capacity();
+        str_pad_into("1", 3, "0", Alignment::Left, &mut out);
+        str_pad_into("2", 3, "-", Alignment::Right, &mut out);
+        assert_eq!(out, "0012--");
+        assert_eq!(out.capacity(), capacity);
+    }
 }
@@ -0,0 +1,223 @@
+use crate::string::normalize_line_endings::split_line_endings;
+use crate::string::{slugify, str_pad, wordwrap, Alignment, Redactor};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+
+type Step<'a> = Box<dyn FnMut(&str, &str) -> String + 'a>;
+
+/// Applies string transforms line by line while copying a reader to a writer,
+/// so inputs of any size are processed in constant memory.
+///
+/// Lines are read with [`BufRead::read_line`] and split like [`str::lines`].
+/// Each step sees the line without its ending, and the original `\n` or
+/// `\r\n` (or nothing, for a last line without one) is written back after the
+/// transformed line. Steps run in the order they were added.
+///
+/// # Example
+/// ```rust
+/// use lo_::{CaseTransform, StreamTransform};
+///
+/// let input = "HelloWorld\r\nfooBar\nlast line";
+/// let mut output = Vec::new();
+/// let lines = StreamTransform::new(input.as_bytes())
+///     .map_lines(|l| l.to_snake_case())
+///     .write_to(&mut output)
+///     .unwrap();
+///
+/// assert_eq!(lines, 3);
+/// assert_eq!(String::from_utf8(output).unwrap(), "hello_world\r\nfoo_bar\nlast_line");
+///
+/// ```
+pub struct StreamTransform<'a, R> {
+    reader: R,
+    steps: Vec<Step<'a>>,
+}
+
+impl<'a, R: Read> StreamTransform<'a, BufReader<R>> {
+    /// Creates a stream transform reading from `reader` through a [`BufReader`].
+    pub fn new(reader: R) -> Self {
+        Self::from_buf_read(BufReader::new(reader))
+    }
+}
+
+impl<'a, R: BufRead> StreamTransform<'a, R> {
+    /// Creates a stream transform reading from an already buffered reader.
+    pub fn from_buf_read(reader: R) -> Self {
+        StreamTransform {
+            reader,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds a step that replaces each line with `f(line)`.
+    pub fn map_lines<F>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&str) -> String + 'a,
+    {
+        self.steps.push(Box::new(move |line, _| f(line)));
+        self
+    }
+
+    /// Adds a step that wraps each line with [`wordwrap`]. The inserted breaks
+    /// use the line's own ending, and later steps see the wrapped line as one
+    /// value.
+    pub fn wordwrap(mut self, width: usize, cut: bool) -> Self {
+        self.steps.push(Box::new(move |line, ending| {
+            let wrapped = wordwrap(line, width, "\n", cut);
+            let wrapped = wrapped.strip_suffix('\n').unwrap_or(&wrapped);
+            if ending == "\r\n" {
+                wrapped.replace('\n', "\r\n")
+            } else {
+                wrapped.to_string()
+            }
+        }));
+        self
+    }
+
+    /// Adds a step that replaces each line with its [`slugify`] slug.
+    pub fn slugify(self) -> Self {
+        self.map_lines(slugify)
+    }
+
+    /// Adds a step that splits each line on `delimiter`, pads the fields with
+    /// spaces through [`str_pad`] to the `(width, alignment)` of their column,
+    /// and joins them with `separator`. Fields without a column are kept as
+    /// they are.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lo_::{Alignment, StreamTransform};
+    ///
+    /// let mut output = Vec::new();
+    /// StreamTransform::new("id,name\n7,ann\n".as_bytes())
+    ///     .pad_columns(",", &[(3, Alignment::Left), (5, Alignment::Right)], " | ")
+    ///     .write_to(&mut output)
+    ///     .unwrap();
+    /// assert_eq!(String::from_utf8(output).unwrap(), " id | name \n  7 | ann  \n");
+    ///
+    /// ```
+    pub fn pad_columns(
+        mut self,
+        delimiter: &str,
+        columns: &[(usize, Alignment)],
+        separator: &str,
+    ) -> Self {
+        let delimiter = delimiter.to_string();
+        let columns = columns.to_vec();
+        let separator = separator.to_string();
+        self.steps.push(Box::new(move |line, _| {
+            let mut out = String::with_capacity(line.len());
+            for (i, field) in line.split(delimiter.as_str()).enumerate() {
+                if i > 0 {
+                    out.push_str(&separator);
+                }
+                match columns.get(i) {
+                    Some(&(width, alignment)) => {
+                        out.push_str(&str_pad(field, width, " ", alignment))
+                    }
+                    None => out.push_str(field),
+                }
+            }
+            out
+        }));
+        self
+    }
+
+    /// Adds a step that redacts each line with `redactor`. Matches cannot span
+    /// lines.
+    pub fn redact(self, redactor: Redactor) -> Self {
+        self.map_lines(move |line| redactor.redact(line).into_owned())
+    }
+
+    /// Reads every line, applies the steps and writes the result to `writer`
+    /// through a [`BufWriter`], returning the number of lines processed.
+    ///
+    /// # Errors
+    /// Returns the first read or write error. Input that is not valid UTF-8
+    /// fails with [`io::ErrorKind::InvalidData`].
+    pub fn write_to<W: Write>(mut self, writer: W) -> io::Result<usize> {
+        let mut out = BufWriter::new(writer);
+        let mut buf = String::new();
+        let mut lines = 0;
+        loop {
+            buf.clear();
+            if self.reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            let (line, ending) = split_line_endings(&buf).next().unwrap_or(("", ""));
+            let mut line = line.to_string();
+            for step in &mut self.steps {
+                line = step(&line, ending);
+            }
+            out.write_all(line.as_bytes())?;
+            out.write_all(ending.as_bytes())?;
+            lines += 1;
+        }
+        out.flush()?;
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::mask_email;
+
+    fn run<'a>(
+        input: &'a str,
+        transform: impl FnOnce(StreamTransform<'a, &'a [u8]>) -> StreamTransform<'a, &'a [u8]>,
+    ) -> String {
+        let mut output = Vec::new();
+        transform(StreamTransform::from_buf_read(input.as_bytes()))
+            .write_to(&mut output)
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_keeps_line_endings() {
+        for input in ["", "a", "a\n", "a\r\nb\n\r\nc", "\n\n", "a\rb\r\n"] {
+            assert_eq!(run(input, |t| t), input);
+        }
+        assert_eq!(
+            run("a\r\nb\n", |t| t.map_lines(|l| l.to_uppercase())),
+            "A\r\nB\n"
+        );
+    }
+
+    #[test]
+    fn test_steps_run_in_order() {
+        let out = run("Hello World\n", |t| {
+            t.map_lines(|l| format!("{l}!"))
+                .slugify()
+                .map_lines(|l| l.replace('-', "_"))
+        });
+        assert_eq!(out, "hello_world\n");
+    }
+
+    #[test]
+    fn test_wordwrap_uses_line_ending() {
+        let out = run("one two three\r\nfour five\n", |t| t.wordwrap(8, false));
+        assert_eq!(out, "one two\r\nthree\r\nfour\nfive\n");
+    }
+
+    #[test]
+    fn test_redact_and_mask() {
+        let out = run("from 10.0.0.7\nto bob@example.com\n", |t| {
+            t.redact(Redactor::new().ipv4())
+                .map_lines(|l| match l.split_once("to ") {
+                    Some((head, email)) => format!("{head}to {}", mask_email(email).unwrap()),
+                    None => l.to_string(),
+                })
+        });
+        assert_eq!(out, "from [IPV4]\nto b**@example.com\n");
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_an_error() {
+        let input: &[u8] = b"ok\n\xff\n";
+        let err = StreamTransform::new(input)
+            .write_to(io::sink())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::string::normalize_line_endings::split_line_endings;
+
+/// Strips leading whitespace followed by `margin` from every line, like Scala's
+/// `stripMargin`.
+///
+/// Lines whose first non-whitespace character is not `margin` are kept as they
+/// are. Line endings are kept as they are.
+///
+/// # Example
+/// ```rust
+/// use lo_::strip_margin;
+///
+/// let query = "SELECT *
+///             |FROM users
+///             |  WHERE id = 1";
+/// assert_eq!(strip_margin(query, '|'), "SELECT *\nFROM users\n  WHERE id = 1");
+///
+/// ```
+pub fn strip_margin(s: &str, margin: char) -> String {
+    let mut out = String::with_capacity(s.len());
+    for (line, end) in split_line_endings(s) {
+        out.push_str(line.trim_start().strip_prefix(margin).unwrap_or(line));
+        out.push_str(end);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_leading_margin_is_stripped() {
+        assert_eq!(
+            strip_margin("  |a|b\r\n  c |d\n#e", '|'),
+            "a|b\r\n  c |d\n#e"
+        );
+        assert_eq!(strip_margin("  #x", '#'), "x");
+    }
+}
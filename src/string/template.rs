@@ -1,5 +1,7 @@
-use regex::Regex;
+use crate::string::*;
+use regex::{Captures, Regex};
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
 /// Replaces placeholders in the given text with corresponding values from a hashmap.
 ///
@@ -19,20 +21,77 @@ use std::collections::HashMap;
 ///
 /// ```
 ///
+/// A key can be followed by filters, applied left to right: `{name|trim|title_case}`.
+/// Spaces around the key and the filters are ignored, so `{ name }` works too.
+/// The filters are `camel_case`, `snake_case`, `kebab_case`, `screaming_snake_case`,
+/// `title_case`, `capitalize`, `lower_first`, `upper_first`, `deburr`, `slugify`,
+/// `pluralize`, `singularize`, `humanize`, `escape_html`, `escape_xml`, `json_escape`,
+/// `percent_encode`, `shell_quote`, `upper`, `lower` and `trim`. Placeholders with a
+/// missing key or an unknown filter are left as they are.
+///
+/// ```rust
+/// use lo_::template;
+/// use std::collections::HashMap;
+/// let values = HashMap::from([("name", "  ada lovelace ")]);
+/// assert_eq!(template("Hi {name|trim|title_case}!", &values), "Hi Ada Lovelace!");
+/// assert_eq!(template("Hi {name|shout}!", &values), "Hi {name|shout}!");
+///
+/// ```
 pub fn template(txt: &str, values: &HashMap<&str, &str>) -> String {
-    let mut parsed_txt = String::from(txt);
+    placeholder_regex()
+        .replace_all(txt, |captures: &Captures<'_>| {
+            render_placeholder(&captures[1], values).unwrap_or_else(|| captures[0].to_string())
+        })
+        .into_owned()
+}
+
+fn placeholder_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\{([^{}]*)\}").unwrap())
+}
 
-    for (key, value) in values.iter() {
-        let pattern = format!("\\{{{key}\\}}");
-        let re = Regex::new(&pattern).unwrap();
-        parsed_txt = re
-            .replace_all(&parsed_txt, |_captures: &regex::Captures<'_>| {
-                value.to_string()
-            })
-            .to_string();
+fn render_placeholder(placeholder: &str, values: &HashMap<&str, &str>) -> Option<String> {
+    let (key, filters) = match placeholder.split_once('|') {
+        Some(parts) => parts,
+        None => {
+            return values
+                .get(placeholder.trim())
+                .map(|value| value.to_string())
+        }
+    };
+    let mut value = values.get(key.trim())?.to_string();
+    for filter in filters.split('|') {
+        value = apply_filter(&value, filter.trim())?;
     }
+    Some(value)
+}
 
-    parsed_txt
+// Keep in sync with the filters of `lo_template!` in `lo_macros`.
+fn apply_filter(value: &str, filter: &str) -> Option<String> {
+    Some(match filter {
+        "camel_case" => camel_case(value),
+        "snake_case" => snake_case(value),
+        "kebab_case" => kebab_case(value),
+        "screaming_snake_case" => screaming_snake_case(value),
+        "title_case" => title_case(value),
+        "capitalize" => capitalize(value),
+        "lower_first" => lower_first(value),
+        "upper_first" => upper_first(value),
+        "deburr" => deburr(value),
+        "slugify" => slugify(value),
+        "pluralize" => pluralize(value),
+        "singularize" => singularize(value),
+        "humanize" => humanize(value),
+        "escape_html" => escape_html(value),
+        "escape_xml" => escape_xml(value),
+        "json_escape" => json_escape(value),
+        "percent_encode" => percent_encode(value),
+        "shell_quote" => shell_quote(value),
+        "upper" => value.to_uppercase(),
+        "lower" => value.to_lowercase(),
+        "trim" => value.trim().to_string(),
+        _ => return None,
+    })
 }
 
 #[cfg(test)]
@@ -66,6 +125,49 @@ mod tests {
         assert_eq!(template(txt, &values), "Thank you for being with {name}.");
     }
 
+    #[test]
+    fn test_values_are_not_re_expanded() {
+        let txt = "{a} and {b.c}";
+        let mut values = HashMap::new();
+        values.insert("a", "{b.c}");
+        values.insert("b.c", "x");
+        assert_eq!(template(txt, &values), "{b.c} and x");
+    }
+
+    #[test]
+    fn test_filters() {
+        let values = HashMap::from([("name", "user account"), ("html", "<b>")]);
+        assert_eq!(template("{name|camel_case}", &values), "userAccount");
+        assert_eq!(
+            template("{ name | pluralize | upper }", &values),
+            "USER ACCOUNTS"
+        );
+        assert_eq!(template("{html|escape_html}", &values), "&lt;b&gt;");
+        assert_eq!(template("{name|nope}", &values), "{name|nope}");
+        assert_eq!(template("{missing|upper}", &values), "{missing|upper}");
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn test_macro_matches_runtime() {
+        let value = " Crème brûlée|spoon ";
+        let values = HashMap::from([("v", value)]);
+        let txt = "{v|camel_case} {v|snake_case} {v|kebab_case} {v|screaming_snake_case} \
+            {v|title_case} {v|capitalize} {v|lower_first} {v|upper_first} {v|deburr} \
+            {v|slugify} {v|pluralize} {v|singularize} {v|humanize} {v|escape_html} \
+            {v|escape_xml} {v|json_escape} {v|percent_encode} {v|shell_quote} \
+            {v|upper} {v|lower} {v|trim} { v } { v | upper }";
+        let expanded = crate::lo_template!(
+            "{v|camel_case} {v|snake_case} {v|kebab_case} {v|screaming_snake_case} \
+            {v|title_case} {v|capitalize} {v|lower_first} {v|upper_first} {v|deburr} \
+            {v|slugify} {v|pluralize} {v|singularize} {v|humanize} {v|escape_html} \
+            {v|escape_xml} {v|json_escape} {v|percent_encode} {v|shell_quote} \
+            {v|upper} {v|lower} {v|trim} { v } { v | upper }",
+            v = value,
+        );
+        assert_eq!(expanded, template(txt, &values));
+    }
+
     #[test]
     fn test_no_pattern_found() {
         let txt = "Thank you for being with us.";
@@ -0,0 +1,438 @@
+use crate::string::display_width::grapheme_width;
+use crate::string::{
+    display_width, str_pad, truncate, wordwrap, Alignment, LengthUnit, TruncateOptions,
+    TruncatePosition,
+};
+use std::fmt;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The borders drawn by a [`TextTable`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableBorder {
+    /// Columns separated by two spaces, without lines.
+    #[default]
+    None,
+    /// `+`, `-` and `|`.
+    Ascii,
+    /// Unicode box-drawing characters.
+    Unicode,
+    /// A GitHub-flavored Markdown table.
+    Markdown,
+}
+
+/// What happens to cells wider than their column's maximum width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CellOverflow {
+    /// Removes the middle of the text and puts `…` in its place, like
+    /// [`truncate_middle`](crate::truncate_middle).
+    #[default]
+    Truncate,
+    /// Wraps the text over several lines with [`wordwrap`], cutting words
+    /// that are still too wide.
+    Wrap,
+}
+
+/// Options for one column of a [`TextTable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableColumn {
+    alignment: Alignment,
+    max_width: Option<usize>,
+    overflow: CellOverflow,
+}
+
+impl Default for TableColumn {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableColumn {
+    /// Creates a column without a maximum width that pads on the right
+    /// (left-aligned text).
+    pub fn new() -> Self {
+        TableColumn {
+            alignment: Alignment::Right,
+            max_width: None,
+            overflow: CellOverflow::Truncate,
+        }
+    }
+
+    /// Sets where padding goes, as in [`str_pad`]: `Alignment::Left` pads on
+    /// the left and so right-aligns the text. The default is `Alignment::Right`.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Limits the column to `max_width` terminal columns.
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets how cells wider than the maximum width are shortened.
+    pub fn overflow(mut self, overflow: CellOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+/// Builds plain-text tables for CLI output and Markdown reports.
+///
+/// Widths are measured in terminal columns with [`display_width`], so wide
+/// characters and emoji line up. Cells are padded with [`str_pad`]; rows may
+/// have different numbers of cells, and missing cells are left empty. Line
+/// breaks inside a cell start a new line within the row, except in Markdown,
+/// where lines are joined with `<br>` and `|` is escaped.
+///
+/// # Example
+/// ```rust
+/// use lo_::{Alignment, TableBorder, TableColumn, TextTable};
+///
+/// let table = TextTable::new()
+///     .header(["Crate", "Downloads"])
+///     .row(["lo_", "1200"])
+///     .row(["日本語", "87"])
+///     .column(1, TableColumn::new().alignment(Alignment::Left))
+///     .border(TableBorder::Ascii);
+///
+/// assert_eq!(
+///     table.render(),
+///     "+--------+-----------+\n\
+///      | Crate  | Downloads |\n\
+///      +--------+-----------+\n\
+///      | lo_    |      1200 |\n\
+///      | 日本語 |        87 |\n\
+///      +--------+-----------+"
+/// );
+///
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextTable {
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    columns: Vec<TableColumn>,
+    border: TableBorder,
+}
+
+impl TextTable {
+    /// Creates an empty table without borders.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the header row.
+    pub fn header<I, S>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.header = Some(to_cells(cells));
+        self
+    }
+
+    /// Appends a row.
+    pub fn row<I, S>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.rows.push(to_cells(cells));
+        self
+    }
+
+    /// Sets the options of the column at `index`, counting from `0`.
+    pub fn column(mut self, index: usize, column: TableColumn) -> Self {
+        if self.columns.len() <= index {
+            self.columns.resize(index + 1, TableColumn::new());
+        }
+        self.columns[index] = column;
+        self
+    }
+
+    /// Sets the border style.
+    pub fn border(mut self, border: TableBorder) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Renders the table, without a trailing newline.
+    pub fn render(&self) -> String {
+        let count = self
+            .header
+            .iter()
+            .chain(&self.rows)
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0);
+        if count == 0 {
+            return String::new();
+        }
+
+        let header = self.header.as_ref().map(|cells| self.layout(cells, count));
+        let rows: Vec<_> = self
+            .rows
+            .iter()
+            .map(|cells| self.layout(cells, count))
+            .collect();
+        // Markdown needs at least `---` in the delimiter row.
+        let min_width = if self.border == TableBorder::Markdown {
+            3
+        } else {
+            0
+        };
+        let widths: Vec<usize> = (0..count)
+            .map(|i| {
+                header
+                    .iter()
+                    .chain(&rows)
+                    .flat_map(|row| &row[i])
+                    .map(|line| display_width(line))
+                    .max()
+                    .unwrap_or(0)
+                    .max(min_width)
+            })
+            .collect();
+
+        let mut lines = Vec::new();
+        match self.border {
+            TableBorder::None => {
+                for row in header.iter().chain(&rows) {
+                    self.push_row(&mut lines, row, &widths, ["", "  ", ""]);
+                }
+                for line in &mut lines {
+                    line.truncate(line.trim_end().len());
+                }
+            }
+            TableBorder::Ascii | TableBorder::Unicode => {
+                let (fill, edges, [top, middle, bottom]) = if self.border == TableBorder::Ascii {
+                    ("-", ["| ", " | ", " |"], [["+", "+", "+"]; 3])
+                } else {
+                    (
+                        "─",
+                        ["│ ", " │ ", " │"],
+                        [["┌", "┬", "┐"], ["├", "┼", "┤"], ["└", "┴", "┘"]],
+                    )
+                };
+                lines.push(rule(&widths, fill, top));
+                if let Some(header) = &header {
+                    self.push_row(&mut lines, header, &widths, edges);
+                    lines.push(rule(&widths, fill, middle));
+                }
+                for row in &rows {
+                    self.push_row(&mut lines, row, &widths, edges);
+                }
+                lines.push(rule(&widths, fill, bottom));
+            }
+            TableBorder::Markdown => {
+                let edges = ["| ", " | ", " |"];
+                // Markdown tables need a header row, so an empty one stands in.
+                let empty = vec![vec![String::new()]; count];
+                self.push_row(
+                    &mut lines,
+                    header.as_ref().unwrap_or(&empty),
+                    &widths,
+                    edges,
+                );
+                let delimiters: Vec<String> = widths
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &width)| match self.column_options(i).alignment {
+                        Alignment::Left => format!("{}:", "-".repeat(width - 1)),
+                        Alignment::Right => format!(":{}", "-".repeat(width - 1)),
+                        Alignment::Center => format!(":{}:", "-".repeat(width - 2)),
+                    })
+                    .collect();
+                lines.push(format!("| {} |", delimiters.join(" | ")));
+                for row in &rows {
+                    self.push_row(&mut lines, row, &widths, edges);
+                }
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn column_options(&self, index: usize) -> TableColumn {
+        self.columns.get(index).copied().unwrap_or_default()
+    }
+
+    /// Splits every cell of a row into the lines it is drawn with.
+    fn layout(&self, cells: &[String], count: usize) -> Vec<Vec<String>> {
+        (0..count)
+            .map(|i| {
+                let cell = cells.get(i).map_or("", String::as_str);
+                self.cell_lines(cell, self.column_options(i))
+            })
+            .collect()
+    }
+
+    fn cell_lines(&self, cell: &str, column: TableColumn) -> Vec<String> {
+        let mut lines: Vec<String> = cell
+            .lines()
+            .flat_map(|line| match column.max_width {
+                Some(width) if column.overflow == CellOverflow::Wrap => wrap_to_width(line, width),
+                Some(width) => {
+                    let options = TruncateOptions::new(width)
+                        .position(TruncatePosition::Middle)
+                        .unit(LengthUnit::Columns);
+                    vec![truncate(line, &options)]
+                }
+                None => vec![line.to_string()],
+            })
+            .collect();
+        if self.border == TableBorder::Markdown {
+            let escaped: Vec<String> = lines.iter().map(|line| line.replace('|', "\\|")).collect();
+            return vec![escaped.join("<br>")];
+        }
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines
+    }
+
+    fn push_row(
+        &self,
+        lines: &mut Vec<String>,
+        row: &[Vec<String>],
+        widths: &[usize],
+        [left, separator, right]: [&str; 3],
+    ) {
+        let height = row.iter().map(Vec::len).max().unwrap_or(0);
+        for k in 0..height {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(i, (cell, &width))| {
+                    let text = cell.get(k).map_or("", String::as_str);
+                    pad_to_width(text, width, self.column_options(i).alignment)
+                })
+                .collect();
+            lines.push(format!("{left}{}{right}", cells.join(separator)));
+        }
+    }
+}
+
+impl fmt::Display for TextTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+fn to_cells<I, S>(cells: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    cells.into_iter().map(|c| c.as_ref().to_string()).collect()
+}
+
+/// Pads `text` with spaces to `width` columns. `str_pad` counts chars, so the
+/// target length is adjusted by the difference between chars and columns.
+fn pad_to_width(text: &str, width: usize, alignment: Alignment) -> String {
+    let missing = width.saturating_sub(display_width(text));
+    str_pad(text, text.chars().count() + missing, " ", alignment)
+}
+
+/// Wraps a line with `wordwrap`, then splits lines that are still wider than
+/// `width` columns, such as long words or wide characters.
+fn wrap_to_width(line: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for wrapped in wordwrap(line, width, "\n", false).lines() {
+        let mut current = String::new();
+        let mut current_width = 0;
+        for grapheme in wrapped.graphemes(true) {
+            let grapheme_width = grapheme_width(grapheme);
+            if current_width + grapheme_width > width && !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            current.push_str(grapheme);
+            current_width += grapheme_width;
+        }
+        lines.push(current);
+    }
+    lines
+}
+
+fn rule(widths: &[usize], fill: &str, [left, middle, right]: [&str; 3]) -> String {
+    let segments: Vec<String> = widths.iter().map(|&width| fill.repeat(width + 2)).collect();
+    format!("{left}{}{right}", segments.join(middle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TextTable {
+        TextTable::new()
+            .header(["id", "name"])
+            .row(["1", "Ann"])
+            .row(["22", "Bob"])
+            .column(0, TableColumn::new().alignment(Alignment::Left))
+    }
+
+    #[test]
+    fn test_no_border() {
+        assert_eq!(sample().render(), "id  name\n 1  Ann\n22  Bob");
+        assert_eq!(TextTable::new().render(), "");
+    }
+
+    #[test]
+    fn test_unicode_border() {
+        assert_eq!(
+            sample().border(TableBorder::Unicode).to_string(),
+            "┌────┬──────┐\n\
+             │ id │ name │\n\
+             ├────┼──────┤\n\
+             │  1 │ Ann  │\n\
+             │ 22 │ Bob  │\n\
+             └────┴──────┘"
+        );
+    }
+
+    #[test]
+    fn test_markdown() {
+        let table = sample()
+            .row(["3", "a|b\nc"])
+            .column(1, TableColumn::new().alignment(Alignment::Center))
+            .border(TableBorder::Markdown);
+        assert_eq!(
+            table.render(),
+            "|  id |   name    |\n\
+             | --: | :-------: |\n\
+             |   1 |    Ann    |\n\
+             |  22 |    Bob    |\n\
+             |   3 | a\\|b<br>c |"
+        );
+    }
+
+    #[test]
+    fn test_truncate_and_wrap() {
+        let table = TextTable::new()
+            .row(["abcdefghij", "one two three"])
+            .row(["日本語テキスト", "四五六七八"])
+            .column(0, TableColumn::new().max_width(5))
+            .column(
+                1,
+                TableColumn::new().max_width(7).overflow(CellOverflow::Wrap),
+            );
+        assert_eq!(
+            table.render(),
+            "ab…ij  one two\n       three\n日…ト  四五六\n       七八"
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows_and_wide_chars() {
+        let table = TextTable::new()
+            .row(["😀", "x", "y"])
+            .row(["a"])
+            .border(TableBorder::Ascii);
+        assert_eq!(
+            table.render(),
+            "+----+---+---+\n| 😀 | x | y |\n| a  |   |   |\n+----+---+---+"
+        );
+    }
+}
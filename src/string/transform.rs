@@ -1,5 +1,5 @@
 use crate::string::*;
-use std::borrow::ToOwned;
+use std::borrow::{Cow, ToOwned};
 use std::collections::HashMap;
 use std::str::FromStr;
 
@@ -20,6 +20,10 @@ use std::str::FromStr;
 /// assert_eq!(s.to_slug(), "hello-world");
 /// assert_eq!(s.to_capitalize(), "Hello_world");
 /// assert_eq!("My Résumé".to_kebab_case(), "my-resume");
+/// assert_eq!("Crème Brûlée".to_deburred(), "Creme Brulee");
+/// assert_eq!(s.to_case(lo_::Case::Camel), "helloWorld");
+/// assert_eq!("LineItem".to_tableized(), "line_items");
+/// assert_eq!("people".to_singular(), "person");
 ///
 /// ```
 pub trait CaseTransform: ToOwned + AsRef<str> {
@@ -47,9 +51,39 @@ pub trait CaseTransform: ToOwned + AsRef<str> {
     fn to_slug(&self) -> String {
         slugify(self.as_ref())
     }
+    fn to_slug_with(&self, options: &SlugOptions) -> String {
+        slugify_with(self.as_ref(), options)
+    }
+    fn to_deburred(&self) -> String {
+        deburr(self.as_ref())
+    }
+    fn to_transliterated(&self) -> String {
+        transliterate(self.as_ref())
+    }
     fn to_capitalize(&self) -> String {
         capitalize(self.as_ref())
     }
+    fn to_case(&self, case: Case) -> String {
+        convert_case(self.as_ref(), case)
+    }
+    fn to_plural(&self) -> String {
+        pluralize(self.as_ref())
+    }
+    fn to_singular(&self) -> String {
+        singularize(self.as_ref())
+    }
+    fn to_humanized(&self) -> String {
+        humanize(self.as_ref())
+    }
+    fn to_tableized(&self) -> String {
+        tableize(self.as_ref())
+    }
+    fn to_classified(&self) -> String {
+        classify(self.as_ref())
+    }
+    fn to_foreign_key(&self) -> String {
+        foreign_key(self.as_ref())
+    }
 }
 
 /// Provides word-based and wrapping utilities.
@@ -65,17 +99,35 @@ pub trait CaseTransform: ToOwned + AsRef<str> {
 /// let words = s.to_words();
 /// assert_eq!(words, vec!["hello", "world", "this", "is", "Rust"]);
 ///
+/// let humps = "fooBar".to_words_with_mode(lo_::WordsMode::Lodash);
+/// assert_eq!(humps, vec!["foo", "Bar"]);
+///
 /// ```
 pub trait WordTransform: ToOwned + AsRef<str> {
     fn to_words(&self) -> Vec<String> {
         words(self.as_ref())
     }
+    fn to_words_with_mode(&self, mode: WordsMode) -> Vec<String> {
+        words_with_mode(self.as_ref(), mode)
+    }
+    fn words_iter(&self, mode: WordsMode) -> WordsIter<'_> {
+        words_iter(self.as_ref(), mode)
+    }
     fn wrap(&self, width: usize, break_str: &str, cut: bool) -> String {
         wordwrap(self.as_ref(), width, break_str, cut)
     }
     fn wordwrap(&self, width: usize, break_str: &str, cut: bool) -> String {
         wordwrap(self.as_ref(), width, break_str, cut)
     }
+    fn wrap_with(&self, options: &WrapOptions) -> String {
+        wrap_with(self.as_ref(), options)
+    }
+    fn wrap_lines<'a>(&'a self, options: &'a WrapOptions) -> WrapLines<'a> {
+        wrap_lines(self.as_ref(), options)
+    }
+    fn wrap_markdown(&self, width: usize) -> String {
+        wrap_markdown(self.as_ref(), width)
+    }
 }
 
 /// Provides miscellaneous string utilities like reversing, splitting,
@@ -96,6 +148,7 @@ pub trait WordTransform: ToOwned + AsRef<str> {
 /// assert_eq!(parts, vec!["hello", "world"]);
 ///
 /// assert_eq!(s.to_truncate_middle(5), "he…ld");
+/// assert_eq!(s.to_truncated(&lo_::TruncateOptions::new(8)), "hello w…");
 ///
 /// let mut values = HashMap::new();
 /// values.insert("name", "Alice");
@@ -105,8 +158,15 @@ pub trait WordTransform: ToOwned + AsRef<str> {
 /// let num: Option<i32> = "123".to_safe_parse();
 /// assert_eq!(num, Some(123));
 ///
+/// let port: Result<u16, _> = "8_080".to_parse_lenient();
+/// assert_eq!(port, Ok(8080));
+///
 /// let padded = s.pad(15, "-", Alignment::Right);
 /// assert_eq!(padded.len(), 15);
+///
+/// assert_eq!("    a\n      b".dedent(), "a\n  b");
+/// assert_eq!("a\nb".indent("> ", |_| true), "> a\n> b");
+/// assert_eq!("x\r\n  |y".strip_margin('|').normalize_line_endings(lo_::LineEnding::Lf), "x\ny");
 /// ```
 pub trait UtilityTransform: ToOwned + AsRef<str> {
     fn str_rev(&self) -> String {
@@ -115,28 +175,107 @@ pub trait UtilityTransform: ToOwned + AsRef<str> {
     fn str_split(&self, delimiter: &str) -> Vec<String> {
         str_split(self.as_ref(), delimiter)
     }
+    fn split_with(&self, options: &SplitOptions) -> Vec<&str> {
+        split_with(self.as_ref(), options)
+    }
+    fn shell_split(&self) -> Result<Vec<Cow<'_, str>>, SplitError> {
+        shell_split(self.as_ref())
+    }
+    fn csv_split(&self, delimiter: char) -> Result<Vec<Cow<'_, str>>, SplitError> {
+        csv_split(self.as_ref(), delimiter)
+    }
     fn to_truncate_middle(&self, max_len: usize) -> String {
         truncate_middle(self.as_ref(), max_len)
     }
+    fn to_truncated(&self, options: &TruncateOptions) -> String {
+        truncate(self.as_ref(), options)
+    }
     fn to_template(&self, values: &HashMap<&str, &str>) -> String {
         template(self.as_ref(), values)
     }
     fn to_safe_parse<T: FromStr>(&self) -> Option<T> {
         safe_parse(self.as_ref())
     }
+    fn to_parse_lenient<T: LenientParse>(&self) -> Result<T, ParseError> {
+        parse_lenient(self.as_ref())
+    }
     fn pad(&self, length: usize, pad_str: &str, pad_type: Alignment) -> String {
         str_pad(self.as_ref(), length, pad_str, pad_type)
     }
+    fn dedent(&self) -> String {
+        dedent(self.as_ref())
+    }
+    fn indent<F: Fn(&str) -> bool>(&self, prefix: &str, predicate: F) -> String {
+        indent(self.as_ref(), prefix, predicate)
+    }
+    fn strip_margin(&self, margin: char) -> String {
+        strip_margin(self.as_ref(), margin)
+    }
+    fn trim_lines(&self) -> String {
+        trim_lines(self.as_ref())
+    }
+    fn normalize_line_endings(&self, ending: LineEnding) -> String {
+        normalize_line_endings(self.as_ref(), ending)
+    }
+}
+
+/// Provides escaping for HTML, XML, regular expressions, shells, JSON and URLs.
+///
+/// # Examples
+///
+/// ```rust
+/// use lo_::EscapeTransform;
+///
+/// assert_eq!("<b>Tom & Jerry</b>".escape_html(), "&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;");
+/// assert_eq!("&lt;b&gt;".unescape_html(), "<b>");
+/// assert_eq!("it's".escape_xml(), "it&apos;s");
+/// assert_eq!("1+1=2?".escape_regex(), r"1\+1=2\?");
+/// assert_eq!("my file".shell_quote(), "'my file'");
+/// assert_eq!("line\n".json_escape(), r"line\n");
+/// assert_eq!("a b/c".percent_encode(), "a%20b%2Fc");
+/// assert_eq!("a%20b".percent_decode(), Ok("a b".to_string()));
+///
+/// ```
+pub trait EscapeTransform: ToOwned + AsRef<str> {
+    fn escape_html(&self) -> String {
+        escape_html(self.as_ref())
+    }
+    fn unescape_html(&self) -> String {
+        unescape_html(self.as_ref())
+    }
+    fn escape_xml(&self) -> String {
+        escape_xml(self.as_ref())
+    }
+    fn unescape_xml(&self) -> String {
+        unescape_xml(self.as_ref())
+    }
+    fn escape_regex(&self) -> String {
+        escape_regex(self.as_ref())
+    }
+    fn shell_quote(&self) -> String {
+        shell_quote(self.as_ref())
+    }
+    fn json_escape(&self) -> String {
+        json_escape(self.as_ref())
+    }
+    fn percent_encode(&self) -> String {
+        percent_encode(self.as_ref())
+    }
+    fn percent_decode(&self) -> Result<String, DecodeError> {
+        percent_decode(self.as_ref())
+    }
 }
 
 // Implement for `str` and `String`
 impl CaseTransform for str {}
 impl WordTransform for str {}
 impl UtilityTransform for str {}
+impl EscapeTransform for str {}
 
 impl CaseTransform for String {}
 impl WordTransform for String {}
 impl UtilityTransform for String {}
+impl EscapeTransform for String {}
 
 #[cfg(test)]
 mod tests {
@@ -174,4 +313,10 @@ mod tests {
     fn test_to_pad_from_string() {
         assert_eq!("42".pad(5, "0", Alignment::Left), "00042");
     }
+
+    #[test]
+    fn test_escape_html_from_string() {
+        let input = String::from("a < b");
+        assert_eq!(input.escape_html(), "a &lt; b");
+    }
 }
@@ -0,0 +1,149 @@
+use crate::string::deburr::{fold, latin};
+
+/// Converts letters to their closest ASCII spelling.
+///
+/// This is [`deburr`](crate::deburr) extended with romanization tables for Cyrillic and Greek.
+///
+/// ### Behavior:
+/// - Strips accents and other combining marks (e.g., "é" → "e").
+/// - Spells out letters that have no decomposition (e.g., "ß" → "ss", "ø" → "o", "æ" → "ae").
+/// - Romanizes Cyrillic and Greek letters (e.g., "Жуков" → "Zhukov", "Αθήνα" → "Athina").
+/// - Keeps the case of the input; a capital letter spelled with several ASCII letters
+///   only capitalizes the first one (e.g., "Щ" → "Shch").
+/// - Leaves characters it has no mapping for (e.g., CJK, emojis) untouched.
+///
+/// ### Examples
+/// ```rust
+/// use lo_::transliterate;
+///
+/// assert_eq!(transliterate("Straße"), "Strasse");
+/// assert_eq!(transliterate("Søren Kierkegård"), "Soren Kierkegard");
+/// assert_eq!(transliterate("Привет, мир"), "Privet, mir");
+/// assert_eq!(transliterate("Ελληνικά"), "Ellinika");
+///
+/// ```
+pub fn transliterate(s: &str) -> String {
+    fold(s, |c| latin(c).or_else(|| cyrillic(c)).or_else(|| greek(c)))
+}
+
+fn cyrillic(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'д' => "d",
+        'е' => "e",
+        'ё' => "yo",
+        'ж' => "zh",
+        'з' => "z",
+        'и' => "i",
+        'й' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' => "",
+        'ы' => "y",
+        'ь' => "",
+        'э' => "e",
+        'ю' => "yu",
+        'я' => "ya",
+        'є' => "ye",
+        'і' => "i",
+        'ї' => "yi",
+        'ґ' => "g",
+        'ў' => "u",
+        'ђ' => "dj",
+        'ј' => "j",
+        'љ' => "lj",
+        'њ' => "nj",
+        'ћ' => "c",
+        'џ' => "dz",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+fn greek(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        'α' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' => "e",
+        'ζ' => "z",
+        'η' => "i",
+        'θ' => "th",
+        'ι' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' => "s",
+        'ς' => "s",
+        'τ' => "t",
+        'υ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' => "o",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latin_extended() {
+        assert_eq!(transliterate("Ærøskøbing"), "Aeroskobing");
+        assert_eq!(transliterate("Łódź"), "Lodz");
+        assert_eq!(transliterate("Đorđe Þór"), "Dorde Thor");
+        assert_eq!(transliterate("Œuvre"), "Oeuvre");
+    }
+
+    #[test]
+    fn test_german_sharp_s() {
+        assert_eq!(transliterate("Fußgängerübergänge"), "Fussgangerubergange");
+        assert_eq!(transliterate("GROẞ"), "GROSS");
+    }
+
+    #[test]
+    fn test_cyrillic() {
+        assert_eq!(transliterate("Щука и ёжик"), "Shchuka i yozhik");
+        assert_eq!(transliterate("Їжак"), "Yizhak");
+        assert_eq!(transliterate("объявление"), "obyavlenie");
+    }
+
+    #[test]
+    fn test_greek() {
+        assert_eq!(transliterate("Αθήνα"), "Athina");
+        assert_eq!(transliterate("ψυχή"), "psychi");
+    }
+
+    #[test]
+    fn test_untouched() {
+        assert_eq!(transliterate("plain ascii 123!"), "plain ascii 123!");
+        assert_eq!(transliterate("中文 🚀"), "中文 🚀");
+        assert_eq!(transliterate(""), "");
+    }
+}
@@ -0,0 +1,30 @@
+use crate::string::normalize_line_endings::split_line_endings;
+
+/// Trims leading and trailing whitespace from every line, keeping the line endings.
+///
+/// # Example
+/// ```rust
+/// use lo_::trim_lines;
+///
+/// assert_eq!(trim_lines("  a  \n\tb\t\r\n   \nc "), "a\nb\r\n\nc");
+///
+/// ```
+pub fn trim_lines(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for (line, end) in split_line_endings(s) {
+        out.push_str(line.trim());
+        out.push_str(end);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_empty_lines() {
+        assert_eq!(trim_lines("\n \n"), "\n\n");
+        assert_eq!(trim_lines(""), "");
+    }
+}
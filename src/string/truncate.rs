@@ -0,0 +1,336 @@
+use crate::string::display_width::grapheme_width;
+use regex::Regex;
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Where [`truncate`] removes text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TruncatePosition {
+    /// Keep the end: `…world`.
+    Start,
+    /// Keep both ends: `he…ld`.
+    Middle,
+    /// Keep the start: `hello…`.
+    #[default]
+    End,
+}
+
+/// How [`truncate`] measures length.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// Unicode scalar values.
+    #[default]
+    Chars,
+    /// Grapheme clusters (user-perceived characters).
+    Graphemes,
+    /// Terminal columns, see [`display_width`](crate::display_width).
+    Columns,
+}
+
+/// Options for [`truncate`].
+///
+/// # Example
+/// ```rust
+/// use lo_::{truncate, LengthUnit, TruncateOptions, TruncatePosition};
+///
+/// let options = TruncateOptions::new(24)
+///     .omission(" [...]")
+///     .position(TruncatePosition::End)
+///     .word_boundary()
+///     .unit(LengthUnit::Graphemes);
+/// assert_eq!(truncate("hi-diddly-ho there, neighborino", &options), "hi-diddly-ho [...]");
+///
+/// ```
+#[derive(Clone, Debug)]
+pub struct TruncateOptions {
+    length: usize,
+    omission: String,
+    position: TruncatePosition,
+    separator: Option<Regex>,
+    unit: LengthUnit,
+}
+
+impl Default for TruncateOptions {
+    fn default() -> Self {
+        Self::new(30)
+    }
+}
+
+impl TruncateOptions {
+    /// Creates options truncating to `length` chars at the end with `…`.
+    pub fn new(length: usize) -> Self {
+        TruncateOptions {
+            length,
+            omission: "…".to_string(),
+            position: TruncatePosition::End,
+            separator: None,
+            unit: LengthUnit::Chars,
+        }
+    }
+
+    /// Sets the maximum length of the result, omission included.
+    pub fn length(mut self, length: usize) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Sets the marker that replaces the removed text. It counts toward the length.
+    pub fn omission(mut self, omission: &str) -> Self {
+        self.omission = omission.to_string();
+        self
+    }
+
+    /// Sets where text is removed.
+    pub fn position(mut self, position: TruncatePosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Cuts at a match of `separator` rather than mid-word, like the
+    /// `separator` option of Lodash's `truncate`. The kept text ends before
+    /// the last match (or starts after the first, when keeping the end).
+    pub fn separator(mut self, separator: Regex) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// Cuts at whitespace rather than mid-word.
+    pub fn word_boundary(self) -> Self {
+        self.separator(Regex::new(r"\s+").unwrap())
+    }
+
+    /// Sets how lengths are measured.
+    pub fn unit(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+}
+
+/// Shortens a string to at most the configured length, replacing the removed
+/// text with an omission marker.
+///
+/// Like Lodash's `_.truncate`, with a choice of where to cut and of measuring
+/// in chars, graphemes or terminal columns. Strings that fit are returned
+/// unchanged. If the omission alone is longer than the length, it is cut to fit.
+///
+/// # Example
+/// ```rust
+/// use lo_::{truncate, LengthUnit, TruncateOptions, TruncatePosition};
+///
+/// let text = "The quick brown fox";
+/// assert_eq!(truncate(text, &TruncateOptions::new(10)), "The quick…");
+/// assert_eq!(truncate(text, &TruncateOptions::new(12).word_boundary()), "The quick…");
+/// assert_eq!(
+///     truncate(text, &TruncateOptions::new(10).position(TruncatePosition::Start)),
+///     "…brown fox"
+/// );
+/// assert_eq!(
+///     truncate("0123456789abcdef", &TruncateOptions::new(9).position(TruncatePosition::Middle)),
+///     "0123…cdef"
+/// );
+///
+/// let wide = TruncateOptions::new(5).unit(LengthUnit::Columns);
+/// assert_eq!(truncate("日本語テキスト", &wide), "日本…");
+///
+/// ```
+pub fn truncate(s: &str, options: &TruncateOptions) -> String {
+    truncate_cow(s, options).into_owned()
+}
+
+/// Like [`truncate`], but borrows the input when it already fits.
+///
+/// # Example
+/// ```rust
+/// use lo_::{truncate_cow, TruncateOptions};
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(truncate_cow("short", &TruncateOptions::new(10)), Cow::Borrowed(_)));
+///
+/// ```
+pub fn truncate_cow<'a>(s: &'a str, options: &TruncateOptions) -> Cow<'a, str> {
+    let units = units(s, options.unit);
+    let total: usize = units.iter().map(|u| u.2).sum();
+    if total <= options.length {
+        return Cow::Borrowed(s);
+    }
+
+    let omission = options.omission.as_str();
+    let omission_units = self::units(omission, options.unit);
+    let omission_len: usize = omission_units.iter().map(|u| u.2).sum();
+    if omission_len >= options.length {
+        let end = take_front(&omission_units, options.length, omission.len());
+        return Cow::Owned(omission[..end].to_string());
+    }
+
+    let budget = options.length - omission_len;
+    let separator = options.separator.as_ref();
+    let (head, tail) = match options.position {
+        TruncatePosition::End => {
+            let end = take_front(&units, budget, s.len());
+            (cut_head(s, end, separator), "")
+        }
+        TruncatePosition::Start => {
+            let start = take_back(&units, budget, 0);
+            ("", cut_tail(s, start, separator))
+        }
+        TruncatePosition::Middle => {
+            let end = take_front(&units, budget / 2, s.len());
+            let used: usize = units.iter().take_while(|u| u.1 <= end).map(|u| u.2).sum();
+            let start = take_back(&units, budget - used, end);
+            (cut_head(s, end, separator), cut_tail(s, start, separator))
+        }
+    };
+
+    let mut out = String::with_capacity(head.len() + omission.len() + tail.len());
+    out.push_str(head);
+    out.push_str(omission);
+    out.push_str(tail);
+    Cow::Owned(out)
+}
+
+/// `(start, end, length)` of every unit of `s`.
+fn units(s: &str, unit: LengthUnit) -> Vec<(usize, usize, usize)> {
+    match unit {
+        LengthUnit::Chars => s
+            .char_indices()
+            .map(|(i, c)| (i, i + c.len_utf8(), 1))
+            .collect(),
+        LengthUnit::Graphemes => s
+            .grapheme_indices(true)
+            .map(|(i, g)| (i, i + g.len(), 1))
+            .collect(),
+        LengthUnit::Columns => s
+            .grapheme_indices(true)
+            .map(|(i, g)| (i, i + g.len(), grapheme_width(g)))
+            .collect(),
+    }
+}
+
+/// Byte end of the longest prefix that fits in `budget` (the whole string ends at `len`).
+fn take_front(units: &[(usize, usize, usize)], budget: usize, len: usize) -> usize {
+    let mut used = 0;
+    for &(start, _, width) in units {
+        if used + width > budget {
+            return start;
+        }
+        used += width;
+    }
+    len
+}
+
+/// Byte start of the longest suffix that fits in `budget`, not starting before `floor`.
+fn take_back(units: &[(usize, usize, usize)], budget: usize, floor: usize) -> usize {
+    let mut used = 0;
+    let mut start = units.last().map_or(floor, |u| u.1);
+    for &(unit_start, _, width) in units.iter().rev() {
+        if unit_start < floor || used + width > budget {
+            break;
+        }
+        used += width;
+        start = unit_start;
+    }
+    start
+}
+
+/// The kept prefix `s[..end]`, shortened to end before the last separator
+/// match unless the cut already falls on a separator.
+fn cut_head<'a>(s: &'a str, end: usize, separator: Option<&Regex>) -> &'a str {
+    let head = &s[..end];
+    let Some(re) = separator else {
+        return head;
+    };
+    let at_boundary = end == s.len() || re.find_at(s, end).is_some_and(|m| m.start() == end);
+    match re.find_iter(head).last() {
+        Some(m) if m.start() > 0 && (m.end() == end || !at_boundary) => &head[..m.start()],
+        _ => head,
+    }
+}
+
+/// The kept suffix `s[start..]`, shortened to start after the first separator
+/// match unless the cut already falls on a separator.
+fn cut_tail<'a>(s: &'a str, start: usize, separator: Option<&Regex>) -> &'a str {
+    let tail = &s[start..];
+    let Some(re) = separator else {
+        return tail;
+    };
+    let at_boundary = start == 0
+        || re
+            .find_iter(&s[..start])
+            .last()
+            .is_some_and(|m| m.end() == start);
+    match re.find(tail) {
+        Some(m) if m.end() < tail.len() && (m.start() == 0 || !at_boundary) => &tail[m.end()..],
+        _ => tail,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lodash_examples() {
+        let text = "hi-diddly-ho there, neighborino";
+        let options = TruncateOptions::new(24).omission("...");
+        assert_eq!(truncate(text, &options), "hi-diddly-ho there, n...");
+        assert_eq!(
+            truncate(
+                text,
+                &options.clone().separator(Regex::new(r",? +").unwrap())
+            ),
+            "hi-diddly-ho there..."
+        );
+        assert_eq!(
+            truncate(text, &options.omission(" [...]")),
+            "hi-diddly-ho there [...]"
+        );
+    }
+
+    #[test]
+    fn test_length_includes_omission() {
+        for position in [
+            TruncatePosition::Start,
+            TruncatePosition::Middle,
+            TruncatePosition::End,
+        ] {
+            for length in 0..12 {
+                let options = TruncateOptions::new(length)
+                    .omission("..")
+                    .position(position);
+                let out = truncate("abcdefghijkl", &options);
+                assert!(out.chars().count() <= length, "{out:?}");
+            }
+        }
+        assert_eq!(
+            truncate("abcdef", &TruncateOptions::new(1).omission("..")),
+            "."
+        );
+        assert_eq!(truncate("abcdef", &TruncateOptions::new(6)), "abcdef");
+    }
+
+    #[test]
+    fn test_units() {
+        let family = "👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧";
+        let graphemes = TruncateOptions::new(2).unit(LengthUnit::Graphemes);
+        assert_eq!(truncate(family, &graphemes), "👨‍👩‍👧…");
+
+        let chars = TruncateOptions::new(2);
+        assert_eq!(truncate("e\u{301}e\u{301}", &chars), "e…");
+
+        let columns = TruncateOptions::new(4)
+            .unit(LengthUnit::Columns)
+            .position(TruncatePosition::Start);
+        assert_eq!(truncate("ab日本", &columns), "…本");
+    }
+
+    #[test]
+    fn test_middle_word_boundary() {
+        let options = TruncateOptions::new(16)
+            .position(TruncatePosition::Middle)
+            .word_boundary();
+        assert_eq!(
+            truncate("alpha beta gamma delta epsilon", &options),
+            "alpha…epsilon"
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 /// Truncates the middle of a string with an ellipsis (…).
 ///
 /// Keeps the start and end characters, trimming the middle to fit the total length.
@@ -13,31 +15,66 @@
 ///
 /// ```
 pub fn truncate_middle(input: &str, max_len: usize) -> String {
+    truncate_middle_cow(input, max_len).into_owned()
+}
+
+/// Like [`truncate_middle`], but borrows the input when it already fits.
+///
+/// # Example
+/// ```rust
+/// use lo_::truncate_middle_cow;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(truncate_middle_cow("short", 10), Cow::Borrowed(_)));
+/// assert_eq!(truncate_middle_cow("hello_world", 7), "hel…rld");
+///
+/// ```
+pub fn truncate_middle_cow(input: &str, max_len: usize) -> Cow<'_, str> {
+    if max_len == 0 || input.chars().nth(max_len).is_none() {
+        return Cow::Borrowed(input);
+    }
+    let mut result = String::with_capacity(max_len * 4);
+    truncate_middle_into(input, max_len, &mut result);
+    Cow::Owned(result)
+}
+
+/// Like [`truncate_middle`], but appends the result to `out` instead of allocating.
+///
+/// # Example
+/// ```rust
+/// use lo_::truncate_middle_into;
+///
+/// let mut out = String::from("id=");
+/// truncate_middle_into("0123456789", 5, &mut out);
+/// assert_eq!(out, "id=01…89");
+///
+/// ```
+pub fn truncate_middle_into(input: &str, max_len: usize, out: &mut String) {
     let len = input.chars().count();
     if max_len == 0 || max_len >= len {
-        return input.to_string();
+        out.push_str(input);
+        return;
     }
-
     if max_len <= 1 {
-        return "…".to_string();
+        out.push('…');
+        return;
     }
 
     let keep = max_len - 1;
     let start = keep / 2;
     let end = keep - start;
+    let head = input
+        .char_indices()
+        .nth(start)
+        .map_or(input.len(), |(i, _)| i);
+    let tail = input
+        .char_indices()
+        .nth(len - end)
+        .map_or(input.len(), |(i, _)| i);
 
-    let mut chars = input.chars();
-    let start_part: String = chars.by_ref().take(start).collect();
-    let end_part: String = input
-        .chars()
-        .rev()
-        .take(end)
-        .collect::<String>()
-        .chars()
-        .rev()
-        .collect();
-
-    format!("{start_part}…{end_part}")
+    out.push_str(&input[..head]);
+    out.push('…');
+    out.push_str(&input[tail..]);
 }
 
 #[cfg(test)]
@@ -69,4 +106,24 @@ mod tests {
     fn test_empty_input() {
         assert_eq!(truncate_middle("", 5), "");
     }
+
+    #[test]
+    fn test_truncate_middle_cow_and_into() {
+        assert!(matches!(
+            truncate_middle_cow("exactly", 7),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            truncate_middle_cow("anything", 0),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(truncate_middle_cow("héllo wörld", 7), "hél…rld");
+
+        let mut out = String::with_capacity(32);
+        let capacity = out.capacity();
+        truncate_middle_into("abcdefgh", 5, &mut out);
+        truncate_middle_into("xy", 5, &mut out);
+        assert_eq!(out, "ab…ghxy");
+        assert_eq!(out.capacity(), capacity);
+    }
 }
@@ -1,3 +1,6 @@
+use crate::string::lower_first::first_alphanumeric;
+use std::borrow::Cow;
+
 /// Converts the first character of string to lower case.
 ///
 /// # Example
@@ -9,19 +12,51 @@
 /// ```
 ///
 pub fn upper_first(string: &str) -> String {
-    let mut result = String::with_capacity(string.len());
-    let mut is_first_char = true;
-
-    for c in string.chars() {
-        if is_first_char && c.is_alphanumeric() {
-            result.push(c.to_ascii_uppercase());
-            is_first_char = false;
-        } else {
-            result.push(c);
+    upper_first_cow(string).into_owned()
+}
+
+/// Like [`upper_first`], but borrows the input when its first character is already upper case.
+///
+/// # Example
+/// ```rust
+/// use lo_::upper_first_cow;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(upper_first_cow("Fred"), Cow::Borrowed(_)));
+/// assert_eq!(upper_first_cow("fred"), "Fred");
+///
+/// ```
+pub fn upper_first_cow(string: &str) -> Cow<'_, str> {
+    match first_alphanumeric(string) {
+        Some((_, c)) if c.is_ascii_lowercase() => {
+            let mut result = String::with_capacity(string.len());
+            upper_first_into(string, &mut result);
+            Cow::Owned(result)
         }
+        _ => Cow::Borrowed(string),
     }
+}
 
-    result
+/// Like [`upper_first`], but appends the result to `out` instead of allocating.
+///
+/// # Example
+/// ```rust
+/// use lo_::upper_first_into;
+///
+/// let mut out = String::from("> ");
+/// upper_first_into("fred", &mut out);
+/// assert_eq!(out, "> Fred");
+///
+/// ```
+pub fn upper_first_into(string: &str, out: &mut String) {
+    match first_alphanumeric(string) {
+        Some((i, c)) => {
+            out.push_str(&string[..i]);
+            out.push(c.to_ascii_uppercase());
+            out.push_str(&string[i + c.len_utf8()..]);
+        }
+        None => out.push_str(string),
+    }
 }
 
 #[cfg(test)]
@@ -48,4 +83,18 @@ mod tests {
         // Test with a string containing numbers and special characters
         assert_eq!(upper_first("123-abc"), "123-abc".to_string());
     }
+
+    #[test]
+    fn test_upper_first_cow_and_into() {
+        assert!(matches!(upper_first_cow("Hello"), Cow::Borrowed(_)));
+        assert!(matches!(upper_first_cow("-"), Cow::Borrowed(_)));
+        assert_eq!(upper_first_cow("  hi"), "  Hi");
+
+        let mut out = String::with_capacity(32);
+        let capacity = out.capacity();
+        upper_first_into("ab", &mut out);
+        upper_first_into("cd", &mut out);
+        assert_eq!(out, "AbCd");
+        assert_eq!(out.capacity(), capacity);
+    }
 }
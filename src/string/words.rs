@@ -1,5 +1,22 @@
 use regex::Regex;
-use std::collections::VecDeque;
+use unicode_segmentation::{UnicodeSegmentation, UnicodeWords};
+
+/// How [`words_with_mode`] and [`words_iter`] split a string into words.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WordsMode {
+    /// Splits on every non-alphanumeric character. This is what [`words`] does.
+    #[default]
+    Simple,
+    /// Matches JavaScript's `_.words`: camel humps and acronyms are split
+    /// (`fooBar` → `foo`, `Bar`; `XMLHttp` → `XML`, `Http`), letters and digits
+    /// are split apart, contractions (`don't`, `Don'tStop` → `Don't`, `Stop`) and
+    /// ordinals (`1st`) are kept whole, and emoji are words of their own, including
+    /// skin tones, flags and ZWJ sequences.
+    Lodash,
+    /// Uses the [Unicode word boundaries](https://www.unicode.org/reports/tr29/) of UAX #29,
+    /// which keep contractions (`don't`) and decimals (`3.14`) together.
+    Uax29,
+}
 
 /// Splits string into an array of its words.
 ///
@@ -36,26 +53,248 @@ pub fn words_with_pattern(string: &str, re: Option<Regex>) -> Vec<String> {
             .find_iter(string)
             .map(|w| w.as_str().to_string())
             .collect(),
-        None => {
-            let mut result = VecDeque::new();
-            let mut word = String::new();
-
-            for char in string.chars() {
-                if char.is_alphanumeric() {
-                    word.push(char);
-                } else if !word.is_empty() {
-                    result.push_back(word);
-                    word = String::new();
-                }
-            }
+        None => words_with_mode(string, WordsMode::Simple),
+    }
+}
+
+/// Splits string into an array of its words using the given [`WordsMode`].
+///
+/// # Example
+/// ```rust
+/// use lo_::{words_with_mode, WordsMode};
+///
+/// assert_eq!(words_with_mode("fooBar baz", WordsMode::Lodash), vec!["foo", "Bar", "baz"]);
+/// assert_eq!(words_with_mode("don't pay 3.14", WordsMode::Uax29), vec!["don't", "pay", "3.14"]);
+/// assert_eq!(words_with_mode("don't pay 3.14", WordsMode::Simple), vec!["don", "t", "pay", "3", "14"]);
+///
+/// ```
+pub fn words_with_mode(string: &str, mode: WordsMode) -> Vec<String> {
+    words_iter(string, mode).map(|w| w.to_string()).collect()
+}
 
-            if !word.is_empty() {
-                result.push_back(word);
+/// Returns an iterator over the words of a string, borrowing each word from the input.
+///
+/// # Example
+/// ```rust
+/// use lo_::{words_iter, WordsMode};
+///
+/// let mut iter = words_iter("XMLHttpRequest", WordsMode::Lodash);
+/// assert_eq!(iter.next(), Some("XML"));
+/// assert_eq!(iter.next(), Some("Http"));
+/// assert_eq!(iter.next(), Some("Request"));
+/// assert_eq!(iter.next(), None);
+///
+/// ```
+pub fn words_iter(string: &str, mode: WordsMode) -> WordsIter<'_> {
+    let inner = match mode {
+        WordsMode::Simple => Inner::Simple(string),
+        WordsMode::Lodash => Inner::Lodash(string),
+        WordsMode::Uax29 => Inner::Uax29(string.unicode_words()),
+    };
+    WordsIter { inner }
+}
+
+/// Iterator returned by [`words_iter`].
+#[derive(Debug)]
+pub struct WordsIter<'a> {
+    inner: Inner<'a>,
+}
+
+#[derive(Debug)]
+enum Inner<'a> {
+    Simple(&'a str),
+    Lodash(&'a str),
+    Uax29(UnicodeWords<'a>),
+}
+
+impl<'a> Iterator for WordsIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        match &mut self.inner {
+            Inner::Simple(rest) => next_word(rest, |c| class(c) != Class::Break, simple_word_len),
+            Inner::Lodash(rest) => next_word(
+                rest,
+                |c| class(c) != Class::Break || is_emoji(c),
+                lodash_word_len,
+            ),
+            Inner::Uax29(words) => words.next(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Upper,
+    Lower,
+    Digit,
+    Break,
+}
+
+fn class(c: char) -> Class {
+    if c.is_uppercase() {
+        Class::Upper
+    } else if c.is_alphabetic() {
+        // Caseless letters (e.g. CJK) behave like lowercase ones.
+        Class::Lower
+    } else if c.is_numeric() {
+        Class::Digit
+    } else {
+        Class::Break
+    }
+}
+
+/// Skips to the next char that starts a word, then splits the word off `rest`.
+fn next_word<'a>(
+    rest: &mut &'a str,
+    starts_word: fn(char) -> bool,
+    word_len: fn(&str) -> usize,
+) -> Option<&'a str> {
+    let start = rest.find(starts_word)?;
+    let tail = &rest[start..];
+    let (word, tail) = tail.split_at(word_len(tail));
+    *rest = tail;
+    Some(word)
+}
+
+/// Byte length of the leading run of chars matching `pred`.
+fn run_len(s: &str, pred: impl Fn(char) -> bool) -> usize {
+    s.find(|c: char| !pred(c)).unwrap_or(s.len())
+}
+
+fn simple_word_len(s: &str) -> usize {
+    run_len(s, char::is_alphanumeric)
+}
+
+fn lodash_word_len(s: &str) -> usize {
+    let first = s.chars().next().map(class);
+    match first {
+        Some(Class::Digit) => {
+            let end = run_len(s, |c| class(c) == Class::Digit);
+            end + ordinal_suffix_len(&s[..end], &s[end..])
+        }
+        Some(Class::Upper) => {
+            let end = run_len(s, |c| class(c) == Class::Upper);
+            let next = s[end..].chars().next().map(class);
+            if next != Some(Class::Lower) {
+                return end + contraction_len(&s[end..]);
+            }
+            if s[..end].chars().count() > 1 {
+                // An acronym followed by a capitalized word: "XMLHttp" → "XML".
+                return s[..end].char_indices().last().map_or(end, |(i, _)| i);
             }
+            let end = end + run_len(&s[end..], |c| class(c) == Class::Lower);
+            end + contraction_len(&s[end..])
+        }
+        Some(Class::Lower) => {
+            let end = run_len(s, |c| class(c) == Class::Lower);
+            end + contraction_len(&s[end..])
+        }
+        _ => emoji_len(s),
+    }
+}
+
+/// Dingbats and astral symbols, which `_.words` treats as emoji.
+fn is_emoji(c: char) -> bool {
+    class(c) == Class::Break && (c >= '\u{10000}' || ('\u{2700}'..='\u{27BF}').contains(&c))
+}
 
-            result.into_iter().collect()
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+/// Variation selectors, skin tones and combining marks that belong to the emoji before them.
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(
+        c,
+        '\u{FE0E}'
+            | '\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{0300}'..='\u{036F}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// Length of the emoji at the start of `s`, with its modifiers and any
+/// zero-width-joined emoji after it. A pair of regional indicators is one flag.
+fn emoji_len(s: &str) -> usize {
+    let mut chars = s.char_indices().peekable();
+    let Some((_, first)) = chars.next().filter(|(_, c)| is_emoji(*c)) else {
+        return 0;
+    };
+    let mut end = first.len_utf8();
+    if is_regional_indicator(first) {
+        if let Some((i, c)) = chars.next_if(|(_, c)| is_regional_indicator(*c)) {
+            end = i + c.len_utf8();
         }
     }
+    while let Some((i, c)) = chars.next() {
+        if is_emoji_modifier(c) {
+            end = i + c.len_utf8();
+        } else if let Some((j, joined)) = chars.next().filter(|_| c == '\u{200D}') {
+            end = j + joined.len_utf8();
+        } else {
+            break;
+        }
+    }
+    end
+}
+
+/// Length of an English contraction (`'s`, `'t`, `'ll`, …) at the start of `rest`,
+/// as long as it ends the word: a break or the end of the text follows, or a
+/// capital starts the next hump (`Don'tStop`). After an uppercase contraction
+/// the capital must start a capitalized word, as in `WON'TStop`.
+fn contraction_len(rest: &str) -> usize {
+    let Some(apostrophe) = rest.chars().next().filter(|c| *c == '\'' || *c == '’') else {
+        return 0;
+    };
+    let after = &rest[apostrophe.len_utf8()..];
+
+    for suffix in ["d", "ll", "m", "re", "s", "t", "ve"] {
+        let Some(candidate) = after.get(..suffix.len()) else {
+            continue;
+        };
+        let mut next = after[suffix.len()..].chars().map(class);
+        let ends_word = match next.next() {
+            None | Some(Class::Break) => true,
+            Some(Class::Upper) => candidate == suffix || next.next() == Some(Class::Lower),
+            _ => false,
+        };
+        if candidate.eq_ignore_ascii_case(suffix) && ends_word {
+            return apostrophe.len_utf8() + suffix.len();
+        }
+    }
+    0
+}
+
+/// Length of an ordinal suffix (`st`, `nd`, `rd`, `th`) matching `digits`.
+fn ordinal_suffix_len(digits: &str, rest: &str) -> usize {
+    let mut tail = digits.chars().rev();
+    let last = tail.next();
+    let tens = tail.next();
+
+    let suffix = match (tens, last) {
+        (Some('1'), _) => "th",
+        (_, Some('1')) => "st",
+        (_, Some('2')) => "nd",
+        (_, Some('3')) => "rd",
+        _ => "th",
+    };
+
+    let matches = rest
+        .get(..suffix.len())
+        .is_some_and(|s| s.eq_ignore_ascii_case(suffix));
+    let ends_word = rest
+        .get(suffix.len()..)
+        .and_then(|s| s.chars().next())
+        .is_none_or(|c| class(c) != Class::Lower);
+
+    if matches && ends_word {
+        suffix.len()
+    } else {
+        0
+    }
 }
 
 #[cfg(test)]
@@ -96,4 +335,71 @@ mod tests {
 
         assert_eq!(ww, vec!["fred", "barney", "&", "pebbles"]);
     }
+
+    #[test]
+    fn test_simple_mode() {
+        let result: Vec<&str> = words_iter("don't stop 3.14 fooBar", WordsMode::Simple).collect();
+        assert_eq!(result, vec!["don", "t", "stop", "3", "14", "fooBar"]);
+    }
+
+    #[test]
+    fn test_lodash_mode_camel_humps() {
+        let lodash = |s| words_iter(s, WordsMode::Lodash).collect::<Vec<_>>();
+        assert_eq!(lodash("fooBar"), vec!["foo", "Bar"]);
+        assert_eq!(lodash("XMLHttpRequest"), vec!["XML", "Http", "Request"]);
+        assert_eq!(
+            lodash("getHTTPResponseCode"),
+            vec!["get", "HTTP", "Response", "Code"]
+        );
+        assert_eq!(
+            lodash("fred, barney, & pebbles"),
+            vec!["fred", "barney", "pebbles"]
+        );
+        assert_eq!(lodash("ÀlaCarte"), vec!["Àla", "Carte"]);
+    }
+
+    #[test]
+    fn test_lodash_mode_numbers_and_contractions() {
+        let lodash = |s| words_iter(s, WordsMode::Lodash).collect::<Vec<_>>();
+        assert_eq!(lodash("html5Parser"), vec!["html", "5", "Parser"]);
+        assert_eq!(lodash("3.14"), vec!["3", "14"]);
+        assert_eq!(
+            lodash("1st 22nd 113th place"),
+            vec!["1st", "22nd", "113th", "place"]
+        );
+        assert_eq!(lodash("don't WON'T it’s"), vec!["don't", "WON'T", "it’s"]);
+        assert_eq!(lodash("'quoted'"), vec!["quoted"]);
+        assert_eq!(lodash("Don'tStop"), vec!["Don't", "Stop"]);
+        assert_eq!(lodash("WON'TStop"), vec!["WON'T", "Stop"]);
+        assert_eq!(lodash("WON'TSTOP"), vec!["WON", "TSTOP"]);
+    }
+
+    #[test]
+    fn test_lodash_mode_emoji() {
+        let lodash = |s| words_iter(s, WordsMode::Lodash).collect::<Vec<_>>();
+        assert_eq!(lodash("hello 🚀 world"), vec!["hello", "🚀", "world"]);
+        assert_eq!(
+            lodash("👍🏽👨\u{200D}👩\u{200D}👧 🇫🇷❤\u{FE0F}go"),
+            vec!["👍🏽", "👨\u{200D}👩\u{200D}👧", "🇫🇷", "❤\u{FE0F}", "go"]
+        );
+        // Simple mode still skips them.
+        assert_eq!(
+            words_iter("hello 🚀 world", WordsMode::Simple).collect::<Vec<_>>(),
+            vec!["hello", "world"]
+        );
+    }
+
+    #[test]
+    fn test_uax29_mode() {
+        let result: Vec<&str> = words_iter("Don't pay $3.14, pal!", WordsMode::Uax29).collect();
+        assert_eq!(result, vec!["Don't", "pay", "3.14", "pal"]);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        for mode in [WordsMode::Simple, WordsMode::Lodash, WordsMode::Uax29] {
+            assert_eq!(words_iter("", mode).count(), 0);
+            assert_eq!(words_iter(" ,.! ", mode).count(), 0);
+        }
+    }
 }
@@ -57,8 +57,8 @@ pub fn wordwrap(s: &str, width: usize, break_str: &str, cut: bool) -> String {
                     start = end;
                 }
             } else {
-                // Regular word fits
-                if current.len() + word.len() + 1 > width {
+                // Regular word fits; `current` already ends with a space
+                if !current.is_empty() && current.len() + word.len() > width {
                     result.push_str(current.trim_end());
                     result.push_str(b_str);
                     current.clear();
@@ -93,6 +93,12 @@ mod tests {
         assert_eq!(wordwrap(input, 9, "\n", true), expected);
     }
 
+    #[test]
+    fn test_wordwrap_exact_fit() {
+        assert_eq!(wordwrap("abcde fg", 5, "\n", false), "abcde\nfg");
+        assert_eq!(wordwrap("ab cd ef", 5, "\n", false), "ab cd\nef");
+    }
+
     #[test]
     fn test_wordwrap_no_cut() {
         let input = "Rust is blazing fast and memory-efficient.";
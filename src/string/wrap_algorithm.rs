@@ -0,0 +1,274 @@
+use crate::string::wrap_lines::{char_len, Line, Piece};
+use crate::string::WrapOptions;
+
+/// Cost added to a line that ends in a hyphenated word, so that breaking
+/// between words is preferred when it is about as good.
+const HYPHEN_PENALTY: u64 = 25;
+
+/// The line breaking algorithm used by [`wrap_lines`](crate::wrap_lines).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WrapAlgorithm {
+    /// Greedy: puts as many words as possible on each line before moving on.
+    /// Fast, but can leave some lines much shorter than others.
+    #[default]
+    FirstFit,
+    /// Minimizes the total raggedness of a paragraph (the sum of the squared
+    /// free space on every line but the last), in the style of Knuth–Plass.
+    ///
+    /// A line longer than the available width costs `overflow_penalty` per
+    /// overflowing character, so a low penalty lets words stick out rather
+    /// than leave a very short line.
+    OptimalFit { overflow_penalty: u64 },
+}
+
+impl WrapAlgorithm {
+    /// [`WrapAlgorithm::OptimalFit`] with the default overflow penalty.
+    pub fn optimal_fit() -> Self {
+        WrapAlgorithm::OptimalFit {
+            overflow_penalty: 2500,
+        }
+    }
+}
+
+/// A unit the line breaker may break after. `soft` atoms end in a hyphenation
+/// point and get a `-` appended when a line ends on them.
+struct Atom {
+    piece: Piece,
+    soft: bool,
+}
+
+/// Breaks a paragraph segment into lines minimizing the total squared slack.
+/// `offset` is the index of the first line within its paragraph.
+pub(crate) fn optimal_fit(
+    pieces: Vec<Piece>,
+    options: &WrapOptions,
+    offset: usize,
+    overflow_penalty: u64,
+) -> Vec<Line> {
+    let atoms = atoms(pieces, options);
+    let n = atoms.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let first_avail = options.available(offset);
+    let rest_avail = options.available(offset + 1);
+
+    // best[i] = (cost, end) of the best layout of atoms[i..] starting on a non-first line.
+    let mut best: Vec<(u64, usize)> = vec![(0, n); n + 1];
+    for i in (0..n).rev() {
+        best[i] = best_line(&atoms, i, rest_avail, overflow_penalty, &best);
+    }
+    let mut breaks = vec![best_line(&atoms, 0, first_avail, overflow_penalty, &best).1];
+    while *breaks.last().unwrap() < n {
+        breaks.push(best[*breaks.last().unwrap()].1);
+    }
+
+    let mut atoms = atoms.into_iter();
+    let mut start = 0;
+    breaks
+        .into_iter()
+        .map(|end| {
+            let mut line: Line = Vec::new();
+            let mut soft_end = false;
+            for atom in atoms.by_ref().take(end - start) {
+                soft_end = atom.soft;
+                line.push(atom.piece);
+            }
+            if soft_end {
+                if let Some(last) = line.last_mut() {
+                    last.text.push('-');
+                }
+            }
+            start = end;
+            line
+        })
+        .collect()
+}
+
+/// Finds the cheapest line starting at atom `i`, given the best costs after it.
+fn best_line(
+    atoms: &[Atom],
+    i: usize,
+    avail: usize,
+    overflow_penalty: u64,
+    best: &[(u64, usize)],
+) -> (u64, usize) {
+    let n = atoms.len();
+    let mut choice = (u64::MAX, i + 1);
+    let mut len = 0;
+
+    for j in i + 1..=n {
+        let atom = &atoms[j - 1];
+        len += char_len(&atom.piece.text) + usize::from(j - 1 > i && atom.piece.space_before);
+        let width = len + usize::from(atom.soft);
+
+        let cost = if width <= avail {
+            let slack = (avail - width) as u64;
+            let line = if j == n { 0 } else { slack * slack };
+            line + if atom.soft { HYPHEN_PENALTY } else { 0 }
+        } else {
+            overflow_penalty.saturating_mul((width - avail) as u64)
+        };
+
+        let total = cost.saturating_add(best[j].0);
+        if total < choice.0 {
+            choice = (total, j);
+        }
+    }
+
+    choice
+}
+
+/// Splits pieces at hyphenation points, and cuts long words if enabled.
+fn atoms(pieces: Vec<Piece>, options: &WrapOptions) -> Vec<Atom> {
+    let limit = options.available(1);
+    let mut atoms = Vec::new();
+
+    for piece in pieces {
+        let mut syllables: Vec<(String, bool)> = match &options.hyphenator {
+            Some(hyphenator) => {
+                let letters = piece
+                    .text
+                    .find(|c: char| !c.is_alphabetic())
+                    .unwrap_or(piece.text.len());
+                let mut parts = hyphenator.hyphenate(&piece.text[..letters]);
+                let last = parts.len() - 1;
+                parts[last] = &piece.text[letters - parts[last].len()..];
+                parts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(k, s)| (s.to_string(), k < last))
+                    .collect()
+            }
+            None => vec![(piece.text.clone(), false)],
+        };
+
+        if options.cut_long_words {
+            syllables = syllables
+                .into_iter()
+                .flat_map(|(text, soft)| cut(&text, limit, soft))
+                .collect();
+        }
+
+        for (k, (text, soft)) in syllables.into_iter().enumerate() {
+            atoms.push(Atom {
+                piece: Piece {
+                    text,
+                    space_before: k == 0 && piece.space_before,
+                },
+                soft,
+            });
+        }
+    }
+
+    atoms
+}
+
+/// Cuts `text` into chunks of at most `limit` chars; only the last chunk keeps `soft`.
+fn cut(text: &str, limit: usize, soft: bool) -> Vec<(String, bool)> {
+    let chars: Vec<char> = text.chars().collect();
+    let count = chars.len().div_ceil(limit).max(1);
+    chars
+        .chunks(limit)
+        .enumerate()
+        .map(|(k, chunk)| (chunk.iter().collect(), soft && k + 1 == count))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::string::*;
+
+    fn both(text: &str, options: WrapOptions) -> (String, String) {
+        let greedy = wrap_with(text, &options);
+        let optimal = wrap_with(text, &options.algorithm(WrapAlgorithm::optimal_fit()));
+        (greedy, optimal)
+    }
+
+    #[test]
+    fn test_optimal_fit_is_less_ragged() {
+        let (greedy, optimal) = both("aaa bb cc ddddd", WrapOptions::new(6));
+        assert_eq!(greedy, "aaa bb\ncc\nddddd");
+        assert_eq!(optimal, "aaa\nbb cc\nddddd");
+    }
+
+    #[test]
+    fn test_optimal_fit_is_never_more_ragged() {
+        let text = "The quick brown fox jumps over the lazy dog and keeps running far away \
+                    until it reaches the river bank at the edge of the old forest";
+        let raggedness = |wrapped: &str, width: usize| -> usize {
+            let lines: Vec<&str> = wrapped.lines().collect();
+            lines[..lines.len() - 1]
+                .iter()
+                .map(|l| (width - l.len()).pow(2))
+                .sum()
+        };
+
+        for width in 12..40 {
+            let (greedy, optimal) = both(text, WrapOptions::new(width));
+            assert!(optimal.lines().all(|l| l.len() <= width));
+            assert!(raggedness(&optimal, width) <= raggedness(&greedy, width));
+        }
+    }
+
+    #[test]
+    fn test_same_result_when_already_even() {
+        let (greedy, optimal) = both("one two three four", WrapOptions::new(9));
+        assert_eq!(greedy, optimal);
+    }
+
+    #[test]
+    fn test_overflow_penalty() {
+        let (greedy, optimal) = both("a verylongword b", WrapOptions::new(6));
+        assert_eq!(greedy, "a\nverylongword\nb");
+        assert_eq!(optimal, greedy);
+
+        let lenient = WrapAlgorithm::OptimalFit {
+            overflow_penalty: 1,
+        };
+        let options = WrapOptions::new(6).algorithm(lenient);
+        assert_eq!(wrap_with("a verylongword b", &options), "a verylongword\nb");
+
+        let options = WrapOptions::new(6)
+            .cut_long_words(true)
+            .algorithm(WrapAlgorithm::optimal_fit());
+        assert_eq!(
+            wrap_with("a verylongword b", &options),
+            "a\nverylo\nngword\nb"
+        );
+    }
+
+    #[test]
+    fn test_default_is_first_fit() {
+        assert_eq!(WrapAlgorithm::default(), WrapAlgorithm::FirstFit);
+        assert_eq!(WrapOptions::new(10).algorithm, WrapAlgorithm::FirstFit);
+    }
+
+    #[test]
+    fn test_optimal_fit_with_indent_and_justify() {
+        let options = WrapOptions::new(8)
+            .initial_indent("> ")
+            .subsequent_indent("> ")
+            .justify(Justify::Full)
+            .algorithm(WrapAlgorithm::optimal_fit());
+        assert_eq!(
+            wrap_with("aaa bb cc ddddd", &options),
+            "> aaa\n> bb  cc\n> ddddd"
+        );
+    }
+
+    #[test]
+    fn test_optimal_fit_hyphenation() {
+        let hyphenator = Hyphenator::new([
+            "hy3ph", "he2n", "hena4", "hen5at", "1na", "n2at", "1tio", "2io", "o2n",
+        ]);
+        let options = WrapOptions::new(11)
+            .hyphenator(hyphenator)
+            .algorithm(WrapAlgorithm::optimal_fit());
+        assert_eq!(
+            wrap_with("the hyphenation rules", &options),
+            "the hyphen-\nation rules"
+        );
+    }
+}
@@ -0,0 +1,517 @@
+use crate::string::wrap_algorithm::optimal_fit;
+use crate::string::{Hyphenator, WrapAlgorithm};
+use std::collections::VecDeque;
+use std::iter::Peekable;
+use std::str::Lines;
+
+/// How wrapped lines are justified within the available width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Justify {
+    /// Ragged right edge.
+    #[default]
+    Left,
+    /// Ragged left edge.
+    Right,
+    /// Centered lines.
+    Center,
+    /// Spaces are stretched so every line but the last of a paragraph fills the width.
+    Full,
+}
+
+/// Options for [`wrap_lines`] and [`wrap_with`].
+///
+/// # Example
+/// ```rust
+/// use lo_::{wrap_with, Justify, WrapOptions};
+///
+/// let options = WrapOptions::new(18)
+///     .initial_indent("* ")
+///     .subsequent_indent("  ")
+///     .justify(Justify::Full);
+///
+/// let text = "Rust is blazing fast and memory efficient.";
+/// assert_eq!(
+///     wrap_with(text, &options),
+///     "* Rust  is blazing\n  fast  and memory\n  efficient."
+/// );
+///
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrapOptions {
+    pub(crate) width: usize,
+    pub(crate) justify: Justify,
+    pub(crate) initial_indent: String,
+    pub(crate) subsequent_indent: String,
+    pub(crate) reflow: bool,
+    pub(crate) break_on_hyphens: bool,
+    pub(crate) cut_long_words: bool,
+    pub(crate) hyphenator: Option<Hyphenator>,
+    pub(crate) algorithm: WrapAlgorithm,
+}
+
+impl WrapOptions {
+    /// Creates options wrapping at `width` characters, indentation included.
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            justify: Justify::Left,
+            initial_indent: String::new(),
+            subsequent_indent: String::new(),
+            reflow: false,
+            break_on_hyphens: false,
+            cut_long_words: false,
+            hyphenator: None,
+            algorithm: WrapAlgorithm::FirstFit,
+        }
+    }
+
+    /// Sets how lines are justified (default [`Justify::Left`]).
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Sets the string prepended to the first line of each paragraph.
+    pub fn initial_indent(mut self, indent: &str) -> Self {
+        self.initial_indent = indent.to_string();
+        self
+    }
+
+    /// Sets the string prepended to every other line of a paragraph.
+    pub fn subsequent_indent(mut self, indent: &str) -> Self {
+        self.subsequent_indent = indent.to_string();
+        self
+    }
+
+    /// Joins the lines of a paragraph before wrapping instead of keeping
+    /// each input line break (default `false`).
+    /// Paragraphs are separated by blank lines, which are always kept.
+    pub fn reflow(mut self, reflow: bool) -> Self {
+        self.reflow = reflow;
+        self
+    }
+
+    /// Allows breaking after the hyphens already in a word, e.g. `memory-|efficient`.
+    pub fn break_on_hyphens(mut self, enabled: bool) -> Self {
+        self.break_on_hyphens = enabled;
+        self
+    }
+
+    /// Force-breaks words longer than the line, like `cut` in [`wordwrap`](crate::wordwrap).
+    pub fn cut_long_words(mut self, enabled: bool) -> Self {
+        self.cut_long_words = enabled;
+        self
+    }
+
+    /// Hyphenates words that do not fit at the end of a line using the given patterns.
+    pub fn hyphenator(mut self, hyphenator: Hyphenator) -> Self {
+        self.hyphenator = Some(hyphenator);
+        self
+    }
+
+    /// Sets the line breaking algorithm (default [`WrapAlgorithm::FirstFit`]).
+    pub fn algorithm(mut self, algorithm: WrapAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Width available for text on a line of a paragraph.
+    pub(crate) fn available(&self, line_index: usize) -> usize {
+        let indent = if line_index == 0 {
+            &self.initial_indent
+        } else {
+            &self.subsequent_indent
+        };
+        self.width.saturating_sub(char_len(indent)).max(1)
+    }
+}
+
+/// Wraps a string into lines according to [`WrapOptions`], yielding one line at a time.
+///
+/// Blank lines are kept as empty lines. Each paragraph is wrapped only when its
+/// first line is requested, so large inputs are never wrapped in one go.
+///
+/// # Example
+/// ```rust
+/// use lo_::{wrap_lines, Justify, WrapOptions};
+///
+/// let options = WrapOptions::new(10).justify(Justify::Right);
+/// let lines: Vec<String> = wrap_lines("Rust is blazing fast\n\nand safe", &options).collect();
+/// assert_eq!(lines, vec!["   Rust is", "   blazing", "      fast", "", "  and safe"]);
+///
+/// ```
+pub fn wrap_lines<'a>(s: &'a str, options: &'a WrapOptions) -> WrapLines<'a> {
+    WrapLines {
+        options,
+        input: s.lines().peekable(),
+        pending: VecDeque::new(),
+    }
+}
+
+/// Wraps a string according to [`WrapOptions`], joining the lines with `\n`.
+///
+/// # Example
+/// ```rust
+/// use lo_::{wrap_with, WrapOptions};
+///
+/// let options = WrapOptions::new(12).break_on_hyphens(true);
+/// assert_eq!(wrap_with("Rust is memory-efficient", &options), "Rust is\nmemory-\nefficient");
+///
+/// let options = WrapOptions::new(20).reflow(true);
+/// assert_eq!(wrap_with("one\ntwo\nthree\n\nfour", &options), "one two three\n\nfour");
+///
+/// ```
+pub fn wrap_with(s: &str, options: &WrapOptions) -> String {
+    wrap_lines(s, options).collect::<Vec<_>>().join("\n")
+}
+
+/// Iterator returned by [`wrap_lines`].
+#[derive(Debug)]
+pub struct WrapLines<'a> {
+    options: &'a WrapOptions,
+    input: Peekable<Lines<'a>>,
+    pending: VecDeque<String>,
+}
+
+impl Iterator for WrapLines<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if let Some(line) = self.pending.pop_front() {
+            return Some(line);
+        }
+
+        let first = self.input.next()?;
+        if first.trim().is_empty() {
+            return Some(String::new());
+        }
+
+        let mut paragraph = vec![first];
+        while let Some(line) = self.input.next_if(|l| !l.trim().is_empty()) {
+            paragraph.push(line);
+        }
+
+        self.pending = wrap_paragraph(&paragraph, self.options).into();
+        self.pending.pop_front()
+    }
+}
+
+/// A word (or part of a word) placed on a line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Piece {
+    pub(crate) text: String,
+    /// Whether a space separates this piece from the previous one.
+    pub(crate) space_before: bool,
+}
+
+pub(crate) type Line = Vec<Piece>;
+
+pub(crate) fn char_len(s: &str) -> usize {
+    s.chars().count()
+}
+
+pub(crate) fn line_len(line: &[Piece]) -> usize {
+    line.iter()
+        .enumerate()
+        .map(|(i, p)| char_len(&p.text) + usize::from(i > 0 && p.space_before))
+        .sum()
+}
+
+/// Wraps the lines of one paragraph and renders them with indents and justification.
+fn wrap_paragraph(paragraph: &[&str], options: &WrapOptions) -> Vec<String> {
+    let segments: Vec<Vec<&str>> = if options.reflow {
+        vec![paragraph.to_vec()]
+    } else {
+        paragraph.iter().map(|line| vec![*line]).collect()
+    };
+
+    let mut rendered = Vec::new();
+    for segment in segments {
+        let pieces = split_pieces(&segment, options.break_on_hyphens);
+        let offset = rendered.len();
+        let lines = break_lines(pieces, options, offset);
+        let count = lines.len();
+        for (i, line) in lines.iter().enumerate() {
+            let index = offset + i;
+            let indent = if index == 0 {
+                &options.initial_indent
+            } else {
+                &options.subsequent_indent
+            };
+            let last = i + 1 == count;
+            rendered.push(render(
+                line,
+                indent,
+                options.available(index),
+                options.justify,
+                last,
+            ));
+        }
+    }
+    rendered
+}
+
+/// Splits text into pieces at whitespace and, optionally, after hyphens
+/// that sit between two alphanumeric characters.
+pub(crate) fn split_pieces(lines: &[&str], break_on_hyphens: bool) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    for word in lines.iter().flat_map(|l| l.split_whitespace()) {
+        let mut space_before = true;
+        let mut start = 0;
+
+        if break_on_hyphens {
+            let chars: Vec<(usize, char)> = word.char_indices().collect();
+            for k in 1..chars.len().saturating_sub(1) {
+                if chars[k].1 == '-'
+                    && chars[k - 1].1.is_alphanumeric()
+                    && chars[k + 1].1.is_alphanumeric()
+                {
+                    let end = chars[k + 1].0;
+                    pieces.push(Piece {
+                        text: word[start..end].to_string(),
+                        space_before,
+                    });
+                    space_before = false;
+                    start = end;
+                }
+            }
+        }
+
+        pieces.push(Piece {
+            text: word[start..].to_string(),
+            space_before,
+        });
+    }
+    pieces
+}
+
+/// Breaks pieces into lines with the configured [`WrapAlgorithm`].
+/// `offset` is the index of the first line within its paragraph.
+pub(crate) fn break_lines(pieces: Vec<Piece>, options: &WrapOptions, offset: usize) -> Vec<Line> {
+    match options.algorithm {
+        WrapAlgorithm::FirstFit => first_fit(pieces, options, offset),
+        WrapAlgorithm::OptimalFit { overflow_penalty } => {
+            optimal_fit(pieces, options, offset, overflow_penalty)
+        }
+    }
+}
+
+/// Greedily fills lines, hyphenating or cutting words that do not fit.
+fn first_fit(pieces: Vec<Piece>, options: &WrapOptions, offset: usize) -> Vec<Line> {
+    let mut queue: VecDeque<Piece> = pieces.into();
+    let mut lines: Vec<Line> = Vec::new();
+    let mut current: Line = Vec::new();
+
+    while let Some(piece) = queue.pop_front() {
+        let avail = options.available(offset + lines.len());
+        let used = line_len(&current);
+        let sep = usize::from(!current.is_empty() && piece.space_before);
+
+        if used + sep + char_len(&piece.text) <= avail {
+            current.push(piece);
+            continue;
+        }
+
+        let room = avail.saturating_sub(used + sep);
+        if let Some((head, tail)) = options
+            .hyphenator
+            .as_ref()
+            .and_then(|h| hyphenate_to_fit(h, &piece.text, room))
+        {
+            current.push(Piece {
+                text: head,
+                space_before: piece.space_before,
+            });
+            lines.push(std::mem::take(&mut current));
+            queue.push_front(Piece {
+                text: tail,
+                space_before: false,
+            });
+            continue;
+        }
+
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+            queue.push_front(piece);
+            continue;
+        }
+
+        if options.cut_long_words {
+            let split = piece
+                .text
+                .char_indices()
+                .nth(avail)
+                .map_or(piece.text.len(), |(i, _)| i);
+            let (head, tail) = piece.text.split_at(split);
+            lines.push(vec![Piece {
+                text: head.to_string(),
+                space_before: false,
+            }]);
+            if !tail.is_empty() {
+                queue.push_front(Piece {
+                    text: tail.to_string(),
+                    space_before: false,
+                });
+            }
+        } else {
+            lines.push(vec![piece]);
+        }
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Splits `word` at the last hyphenation point whose head (plus `-`) fits in `room`.
+pub(crate) fn hyphenate_to_fit(
+    hyphenator: &Hyphenator,
+    word: &str,
+    room: usize,
+) -> Option<(String, String)> {
+    let letters = word
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(word.len());
+    let offsets: Vec<usize> = word.char_indices().map(|(i, _)| i).collect();
+
+    hyphenator
+        .break_points(&word[..letters])
+        .into_iter()
+        .rev()
+        .find(|&point| point < room)
+        .map(|point| {
+            let (head, tail) = word.split_at(offsets[point]);
+            (format!("{head}-"), tail.to_string())
+        })
+}
+
+/// Renders a line with its indent, justified within `avail` characters.
+pub(crate) fn render(
+    line: &[Piece],
+    indent: &str,
+    avail: usize,
+    justify: Justify,
+    last: bool,
+) -> String {
+    let len = line_len(line);
+    let slack = avail.saturating_sub(len);
+    let mut out = String::from(indent);
+
+    let gaps = line.iter().skip(1).filter(|p| p.space_before).count();
+
+    match justify {
+        Justify::Right => out.push_str(&" ".repeat(slack)),
+        Justify::Center => out.push_str(&" ".repeat(slack / 2)),
+        _ => {}
+    }
+
+    let stretch = justify == Justify::Full && !last && gaps > 0;
+    let mut gap = 0;
+    for (i, piece) in line.iter().enumerate() {
+        if i > 0 && piece.space_before {
+            out.push(' ');
+            if stretch {
+                let extra = slack / gaps + usize::from(gap < slack % gaps);
+                out.push_str(&" ".repeat(extra));
+            }
+            gap += 1;
+        }
+        out.push_str(&piece.text);
+    }
+
+    let trimmed = out.trim_end().len();
+    out.truncate(trimmed);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_left_matches_wordwrap() {
+        let text = "Rust is blazing fast and memory-efficient.";
+        let options = WrapOptions::new(10);
+        assert_eq!(
+            wrap_with(text, &options),
+            crate::string::wordwrap(text, 10, "\n", false)
+        );
+    }
+
+    #[test]
+    fn test_justify_center_and_full() {
+        let options = WrapOptions::new(11).justify(Justify::Center);
+        assert_eq!(wrap_with("a bb ccc dddd", &options), " a bb ccc\n   dddd");
+
+        let options = WrapOptions::new(12).justify(Justify::Full);
+        assert_eq!(
+            wrap_with("one two three four five", &options),
+            "one      two\nthree   four\nfive"
+        );
+    }
+
+    #[test]
+    fn test_indents() {
+        let options = WrapOptions::new(12)
+            .initial_indent("- ")
+            .subsequent_indent("  ");
+        assert_eq!(
+            wrap_with("first item is long\n\nsecond", &options),
+            "- first item\n  is long\n\n- second"
+        );
+    }
+
+    #[test]
+    fn test_keeps_blank_lines_and_breaks() {
+        let options = WrapOptions::new(40);
+        let lines: Vec<String> = wrap_lines("a\nb\n\n\nc", &options).collect();
+        assert_eq!(lines, vec!["a", "b", "", "", "c"]);
+
+        let options = WrapOptions::new(40).reflow(true);
+        let lines: Vec<String> = wrap_lines("a\nb\n\n\nc", &options).collect();
+        assert_eq!(lines, vec!["a b", "", "", "c"]);
+    }
+
+    #[test]
+    fn test_break_on_hyphens() {
+        let options = WrapOptions::new(10).break_on_hyphens(true);
+        assert_eq!(
+            wrap_with("a well-known fact", &options),
+            "a well-\nknown fact"
+        );
+
+        let options = WrapOptions::new(10);
+        assert_eq!(
+            wrap_with("a well-known fact", &options),
+            "a\nwell-known\nfact"
+        );
+    }
+
+    #[test]
+    fn test_hyphenator() {
+        let hyphenator = Hyphenator::new([
+            "hy3ph", "he2n", "hena4", "hen5at", "1na", "n2at", "1tio", "2io", "o2n",
+        ]);
+        let options = WrapOptions::new(12).hyphenator(hyphenator);
+        assert_eq!(
+            wrap_with("the hyphenation rules", &options),
+            "the hyphen-\nation rules"
+        );
+    }
+
+    #[test]
+    fn test_cut_long_words() {
+        let options = WrapOptions::new(4).cut_long_words(true);
+        assert_eq!(wrap_with("abcdefghij", &options), "abcd\nefgh\nij");
+
+        let options = WrapOptions::new(4);
+        assert_eq!(wrap_with("abcdefghij ab", &options), "abcdefghij\nab");
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let options = WrapOptions::new(10);
+        assert_eq!(wrap_lines("", &options).count(), 0);
+    }
+}
@@ -0,0 +1,492 @@
+use crate::string::wrap_lines::{break_lines, render, Piece};
+use crate::string::{Justify, WrapOptions};
+
+/// Wraps Markdown text to `width` characters without breaking its structure.
+///
+/// - List items keep a hanging indent under their `- `, `* `, `+ `, `1. ` or `1) ` marker.
+/// - Block quotes keep their `> ` prefix on every wrapped line.
+/// - Fenced code blocks, indented code, tables, ATX and setext headings and thematic breaks are
+///   left untouched.
+/// - Inline code spans and links are never broken.
+/// - Hard line breaks (two trailing spaces or a trailing `\`) are kept.
+///
+/// Lines of the same paragraph or list item are reflowed together. A `width`
+/// of `0` returns the input unchanged.
+///
+/// # Example
+/// ```rust
+/// use lo_::wrap_markdown;
+///
+/// let text = "- Install with `cargo add lo_` and read the [docs](https://docs.rs/lo_)\n\
+///             > Quoted text gets its prefix on every line";
+/// assert_eq!(
+///     wrap_markdown(text, 24),
+///     "- Install with\n  `cargo add lo_` and\n  read the\n  [docs](https://docs.rs/lo_)\n\
+///      > Quoted text gets its\n> prefix on every line"
+/// );
+///
+/// ```
+pub fn wrap_markdown(s: &str, width: usize) -> String {
+    if width == 0 {
+        return s.to_string();
+    }
+
+    let mut out: Vec<String> = Vec::new();
+    let mut block: Option<Block> = None;
+    let mut fence: Option<(char, usize)> = None;
+    let mut table: Option<usize> = None;
+    let mut lines = s.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let (quote, body) = split_quote(line);
+        let trimmed = body.trim_start();
+
+        if let Some((fence_char, fence_len)) = fence {
+            if fence_run(trimmed).is_some_and(|(c, n)| c == fence_char && n >= fence_len) {
+                fence = None;
+            }
+            out.push(line.to_string());
+            continue;
+        }
+
+        if let Some(run) = fence_run(trimmed) {
+            flush(&mut block, &mut out, width);
+            fence = Some(run);
+            table = None;
+            out.push(line.to_string());
+            continue;
+        }
+
+        let indent = body.len() - trimmed.len();
+        let quote_depth = depth(quote);
+        let is_code = indent >= 4 && block.as_ref().is_none_or(|b| b.depth != quote_depth);
+
+        // A table runs from its header row to the next blank line, and its
+        // rows do not need a leading pipe.
+        if trimmed.is_empty() || table.is_some_and(|d| d != quote_depth) {
+            table = None;
+        }
+        if table.is_none() && !is_code && starts_table(trimmed, lines.peek().copied(), quote_depth)
+        {
+            flush(&mut block, &mut out, width);
+            table = Some(quote_depth);
+        }
+
+        // A setext underline turns the paragraph above it into a heading,
+        // which is kept as written.
+        if table.is_none() && is_setext_underline(body) {
+            if let Some(heading) =
+                block.take_if(|b| b.depth == quote_depth && b.first == b.next && !b.text.is_empty())
+            {
+                out.extend(heading.lines);
+                out.push(line.to_string());
+                continue;
+            }
+        }
+
+        if table.is_some() || trimmed.is_empty() || is_code || is_verbatim(trimmed) {
+            flush(&mut block, &mut out, width);
+            out.push(line.to_string());
+            continue;
+        }
+
+        if let Some(marker) = list_marker(trimmed) {
+            flush(&mut block, &mut out, width);
+            let first = format!("{}{}", quote, &body[..indent + marker]);
+            let next = format!("{}{}", quote, " ".repeat(indent + marker));
+            block = Some(Block::new(first, next, depth(quote)));
+            push_text(&mut block, &mut out, &trimmed[marker..], width);
+            continue;
+        }
+
+        match &block {
+            Some(b) if b.depth == depth(quote) => {}
+            _ => {
+                flush(&mut block, &mut out, width);
+                let prefix = format!("{}{}", quote, &body[..indent]);
+                block = Some(Block::new(prefix.clone(), prefix, depth(quote)));
+            }
+        }
+        if let Some(b) = block.as_mut() {
+            b.lines.push(line.to_string());
+        }
+        push_text(&mut block, &mut out, trimmed, width);
+    }
+    flush(&mut block, &mut out, width);
+
+    let mut result = out.join("\n");
+    if s.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// A paragraph or list item being collected for wrapping.
+struct Block {
+    first: String,
+    next: String,
+    depth: usize,
+    text: String,
+    // The source lines of a paragraph, kept in case it turns out to be a
+    // setext heading.
+    lines: Vec<String>,
+}
+
+impl Block {
+    fn new(first: String, next: String, depth: usize) -> Self {
+        Self {
+            first,
+            next,
+            depth,
+            text: String::new(),
+            lines: Vec::new(),
+        }
+    }
+}
+
+/// Appends a line of text to the current block, flushing it on a hard line break.
+fn push_text(block: &mut Option<Block>, out: &mut Vec<String>, text: &str, width: usize) {
+    let Some(b) = block.as_mut() else {
+        return;
+    };
+    if !b.text.is_empty() {
+        b.text.push(' ');
+    }
+    b.text.push_str(text.trim_end());
+
+    if text.ends_with("  ") || text.ends_with('\\') {
+        let (next, depth) = (b.next.clone(), b.depth);
+        flush(block, out, width);
+        if text.ends_with("  ") {
+            if let Some(last) = out.last_mut() {
+                last.push_str("  ");
+            }
+        }
+        *block = Some(Block::new(next.clone(), next, depth));
+    }
+}
+
+/// Wraps the current block, if any, and appends its lines to `out`.
+fn flush(block: &mut Option<Block>, out: &mut Vec<String>, width: usize) {
+    let Some(b) = block.take() else {
+        return;
+    };
+    let pieces = pieces(&b.text);
+    if pieces.is_empty() {
+        // An empty list item; blocks left over after a hard break have no marker.
+        if b.first != b.next {
+            out.push(b.first.trim_end().to_string());
+        }
+        return;
+    }
+
+    let options = WrapOptions::new(width)
+        .initial_indent(&b.first)
+        .subsequent_indent(&b.next);
+    let lines = break_lines(pieces, &options, 0);
+    for (i, line) in lines.iter().enumerate() {
+        let indent = if i == 0 { &b.first } else { &b.next };
+        out.push(render(
+            line,
+            indent,
+            options.available(i),
+            Justify::Left,
+            i + 1 == lines.len(),
+        ));
+    }
+}
+
+/// Splits text at whitespace, keeping inline code spans and links in one piece.
+fn pieces(text: &str) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            if !current.is_empty() {
+                pieces.push(Piece {
+                    text: std::mem::take(&mut current),
+                    space_before: true,
+                });
+            }
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+        let len = span_len(rest).unwrap_or(c.len_utf8());
+        current.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+
+    if !current.is_empty() {
+        pieces.push(Piece {
+            text: current,
+            space_before: true,
+        });
+    }
+    pieces
+}
+
+/// Byte length of an inline code span or link starting `s`, if there is one.
+fn span_len(s: &str) -> Option<usize> {
+    if s.starts_with('`') {
+        let ticks = s.len() - s.trim_start_matches('`').len();
+        let mut search = ticks;
+        while let Some(found) = s[search..].find('`') {
+            let start = search + found;
+            let run = s[start..].len() - s[start..].trim_start_matches('`').len();
+            if run == ticks {
+                return Some(start + run);
+            }
+            search = start + run;
+        }
+        return None;
+    }
+
+    let open = if s.starts_with("![") {
+        1
+    } else if s.starts_with('[') {
+        0
+    } else {
+        return None;
+    };
+    let label = open + closing(&s[open..], '[', ']')?;
+    let target = s[label..]
+        .starts_with('(')
+        .then(|| closing(&s[label..], '(', ')'))
+        .flatten()?;
+    Some(label + target)
+}
+
+/// Byte length up to and including the bracket that closes the one starting `s`.
+fn closing(s: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i + c.len_utf8());
+            }
+        }
+    }
+    None
+}
+
+/// Splits a line into its block quote prefix (e.g. `> > `) and the rest.
+fn split_quote(line: &str) -> (&str, &str) {
+    let mut end = 0;
+    loop {
+        let rest = &line[end..];
+        let spaces = rest.len() - rest.trim_start_matches(' ').len();
+        if spaces > 3 || !rest[spaces..].starts_with('>') {
+            break;
+        }
+        end += spaces + 1;
+        if line[end..].starts_with(' ') {
+            end += 1;
+        }
+    }
+    line.split_at(end)
+}
+
+fn depth(quote: &str) -> usize {
+    quote.matches('>').count()
+}
+
+/// The fence character and length if `line` opens or closes a code fence.
+fn fence_run(line: &str) -> Option<(char, usize)> {
+    let c = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = line.len() - line.trim_start_matches(c).len();
+    (len >= 3).then_some((c, len))
+}
+
+/// Tables, headings and thematic breaks, which are never wrapped.
+fn is_verbatim(line: &str) -> bool {
+    let thematic = line.chars().filter(|c| !c.is_whitespace()).count() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|m| line.chars().all(|c| c == *m || c.is_whitespace()));
+    line.starts_with('|') || is_atx_heading(line) || thematic
+}
+
+/// One to six `#` followed by a space or the end of the line, so `#hashtag`
+/// is plain text.
+fn is_atx_heading(line: &str) -> bool {
+    let rest = line.trim_start_matches('#');
+    let level = line.len() - rest.len();
+    (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with([' ', '\t']))
+}
+
+/// A run of `=` or `-` indented by at most three spaces.
+fn is_setext_underline(body: &str) -> bool {
+    let trimmed = body.trim_start();
+    let underline = trimmed.trim_end();
+    body.len() - trimmed.len() <= 3
+        && ['=', '-']
+            .iter()
+            .any(|m| !underline.is_empty() && underline.chars().all(|c| c == *m))
+}
+
+/// Whether `line` is the header row of a table, i.e. it has a pipe and the
+/// next line, at the same quote depth, is a delimiter row like `--- | :---:`
+/// with as many cells.
+fn starts_table(line: &str, next: Option<&str>, quote_depth: usize) -> bool {
+    let Some(next) = next else {
+        return false;
+    };
+    let (quote, body) = split_quote(next);
+    let delimiter = body.trim();
+    if !line.contains('|') || !delimiter.contains('|') || depth(quote) != quote_depth {
+        return false;
+    }
+    let cells = table_cells(delimiter);
+    let is_delimiter = cells.iter().all(|cell| {
+        let dashes = cell.strip_prefix(':').unwrap_or(cell);
+        let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+        !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+    });
+    is_delimiter && cells.len() == table_cells(line.trim()).len()
+}
+
+/// The trimmed cells of a table row, without its outer pipes.
+fn table_cells(row: &str) -> Vec<&str> {
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = row.strip_suffix('|').unwrap_or(row);
+    row.split('|').map(str::trim).collect()
+}
+
+/// Byte length of a list marker and the space after it, e.g. `- ` or `12. `.
+fn list_marker(line: &str) -> Option<usize> {
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let marker = match line[digits..].chars().next()? {
+        '-' | '*' | '+' if digits == 0 => 1,
+        '.' | ')' if (1..=9).contains(&digits) => digits + 1,
+        _ => return None,
+    };
+    let rest = &line[marker..];
+    if rest.is_empty() {
+        Some(marker)
+    } else if rest.starts_with(' ') {
+        Some(marker + 1)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paragraphs_are_reflowed() {
+        let text = "Rust is blazing fast\nand memory efficient.\n\nSecond paragraph here.";
+        assert_eq!(
+            wrap_markdown(text, 16),
+            "Rust is blazing\nfast and memory\nefficient.\n\nSecond paragraph\nhere."
+        );
+    }
+
+    #[test]
+    fn test_list_items_hang() {
+        let text = "- one two three four five\n- six\n10. seven eight nine ten\n  eleven";
+        assert_eq!(
+            wrap_markdown(text, 12),
+            "- one two\n  three four\n  five\n- six\n10. seven\n    eight\n    nine ten\n    eleven"
+        );
+    }
+
+    #[test]
+    fn test_nested_lists_and_quotes() {
+        let text = "- outer item text\n  - inner item text here\n> > quoted words that wrap";
+        assert_eq!(
+            wrap_markdown(text, 14),
+            "- outer item\n  text\n  - inner item\n    text here\n> > quoted\n> > words that\n> > wrap"
+        );
+    }
+
+    #[test]
+    fn test_code_and_tables_untouched() {
+        let text = "```rust\nlet x = some_really_long_function_name(argument_one);\n```\n\
+                    | a long table cell | another long table cell |\n\
+                    |---|---|\n\
+                    # A heading that is much longer than the width\n\
+                    ---";
+        assert_eq!(wrap_markdown(text, 10), text);
+
+        let quoted = "> ~~~\n> keep    this    spacing\n> ~~~";
+        assert_eq!(wrap_markdown(quoted, 8), quoted);
+    }
+
+    #[test]
+    fn test_tables_without_outer_pipes() {
+        let text = "a long cell | b\n--- | :---:\nc | d e f g\n\nafter the table";
+        assert_eq!(
+            wrap_markdown(text, 8),
+            "a long cell | b\n--- | :---:\nc | d e f g\n\nafter\nthe\ntable"
+        );
+        // A delimiter row needs as many cells as the header.
+        assert_eq!(
+            wrap_markdown("a | b | c\n--- | ---", 40),
+            "a | b | c --- | ---"
+        );
+    }
+
+    #[test]
+    fn test_setext_headings() {
+        assert_eq!(wrap_markdown("Short\n===\n", 40), "Short\n===\n");
+        assert_eq!(
+            wrap_markdown("A heading wider than the width\n---\ntext", 10),
+            "A heading wider than the width\n---\ntext"
+        );
+        assert_eq!(
+            wrap_markdown("> Quoted heading\n> ===", 8),
+            "> Quoted heading\n> ==="
+        );
+        // Without a paragraph above, `===` is plain text.
+        assert_eq!(wrap_markdown("===\nmore", 40), "=== more");
+    }
+
+    #[test]
+    fn test_headings_need_a_space() {
+        assert_eq!(
+            wrap_markdown("#rust and #hashtags wrap", 10),
+            "#rust and\n#hashtags\nwrap"
+        );
+        assert_eq!(wrap_markdown("## A long heading", 5), "## A long heading");
+        assert_eq!(wrap_markdown("#", 5), "#");
+    }
+
+    #[test]
+    fn test_inline_code_and_links_are_atomic() {
+        let text = "Run `cargo test --workspace` then see [the guide](https://example.com/a b) now";
+        assert_eq!(
+            wrap_markdown(text, 12),
+            "Run\n`cargo test --workspace`\nthen see\n[the guide](https://example.com/a b)\nnow"
+        );
+        assert_eq!(
+            wrap_markdown("an ![image alt](img.png) here", 8),
+            "an\n![image alt](img.png)\nhere"
+        );
+        // Unclosed spans are plain text.
+        assert_eq!(wrap_markdown("a `b c", 3), "a\n`b\nc");
+    }
+
+    #[test]
+    fn test_hard_breaks_are_kept() {
+        let text = "- first line  \n  second line\\\n  third";
+        assert_eq!(
+            wrap_markdown(text, 40),
+            "- first line  \n  second line\\\n  third"
+        );
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        assert_eq!(wrap_markdown("", 10), "");
+        assert_eq!(wrap_markdown("keep\n", 10), "keep\n");
+        assert_eq!(wrap_markdown("a b c", 0), "a b c");
+        assert_eq!(wrap_markdown("-\n- x", 10), "-\n- x");
+    }
+}
@@ -0,0 +1,86 @@
+//! Checks that the `_cow` variants borrow input that is already in shape and
+//! that the `_into` variants do not allocate when the buffer has room.
+
+use lo_::{
+    capitalize_cow, capitalize_into, lower_first_cow, lower_first_into, str_pad_cow, str_pad_into,
+    truncate_middle_cow, truncate_middle_into, upper_first_cow, upper_first_into, Alignment,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+// Counts allocations per thread, so tests running in parallel do not see
+// each other's.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn test_cow_borrows_unchanged_input() {
+    // Input that needs changes is copied, which shows allocations are counted.
+    assert_eq!(allocations(|| drop(capitalize_cow("ready"))), 1);
+
+    assert_eq!(allocations(|| drop(capitalize_cow("Ready to ship"))), 0);
+    assert_eq!(allocations(|| drop(lower_first_cow("fooBar"))), 0);
+    assert_eq!(allocations(|| drop(upper_first_cow("FooBar"))), 0);
+    assert_eq!(
+        allocations(|| drop(str_pad_cow("12345", 5, "0", Alignment::Left))),
+        0
+    );
+    assert_eq!(
+        allocations(|| drop(truncate_middle_cow("src/lib.rs", 24))),
+        0
+    );
+}
+
+#[test]
+fn test_into_does_not_allocate() {
+    let long_path = "src/string/very/deeply/nested/module/path/lib.rs";
+    let mut out = String::with_capacity(64);
+    let mut count = |f: &dyn Fn(&mut String)| {
+        out.clear();
+        allocations(|| f(&mut out))
+    };
+
+    for input in ["Ready to ship", "rEADY TO SHIP"] {
+        assert_eq!(count(&|out| capitalize_into(input, out)), 0, "{input}");
+    }
+    for input in ["fooBar", "FooBar"] {
+        assert_eq!(count(&|out| lower_first_into(input, out)), 0, "{input}");
+        assert_eq!(count(&|out| upper_first_into(input, out)), 0, "{input}");
+    }
+    for input in ["12345", "42"] {
+        let pad = |out: &mut String| str_pad_into(input, 5, "0", Alignment::Left, out);
+        assert_eq!(count(&pad), 0, "{input}");
+    }
+    for input in ["src/lib.rs", long_path] {
+        let truncate = |out: &mut String| truncate_middle_into(input, 24, out);
+        assert_eq!(count(&truncate), 0, "{input}");
+    }
+}